@@ -86,6 +86,13 @@ impl FilePicker {
                 if !errs.is_empty() {
                     return Err(FileError::Validation(errs));
                 }
+                let warnings = validate::warnings(world);
+                if !warnings.is_empty() {
+                    TOASTS.lock().warning(format!(
+                        "Warnings:\n{}",
+                        warnings.join("\n")
+                    ));
+                }
 
                 let name = if let Some(name) = self.filename() {
                     format!("{name}.world")
@@ -49,17 +49,12 @@ pub fn nonneg_float<'a>(value: &'a mut f32) -> Input<NonNeg<'a>> {
     Input::new(NonNeg(value))
 }
 
-pub fn precise_float<'a, const U: usize>(value: &'a mut f32) -> Input<Precise<'a, U>> {
-    Input::new(Precise(value))
-}
-
 pub struct Toggle<'a>(&'a mut bool, &'static str, &'static str);
 pub struct ToggleEnum<'a, E: Display + PartialEq + IntoEnumIterator>(&'a mut E);
 pub struct Lock<'a>(&'a mut bool);
 pub struct Percent<'a>(&'a mut f32);
 pub struct Share<'a>(&'a mut f32);
 pub struct NonNeg<'a>(&'a mut f32);
-pub struct Precise<'a, const U: usize>(&'a mut f32);
 pub struct LongText<'a>(&'a mut String);
 pub struct Heading<'a>(&'a mut String);
 
@@ -196,6 +191,26 @@ impl Editable for &mut Option<f32> {
     }
 }
 
+impl Editable for &mut Option<usize> {
+    fn edit(self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            let mut enable = self.is_some();
+            ui.add(edit(&mut enable));
+
+            if let Some(value) = self {
+                ui.add(edit(value));
+            }
+
+            if enable != self.is_some() {
+                *self = match self {
+                    Some(_) => None,
+                    None => Some(0),
+                };
+            }
+        });
+    }
+}
+
 impl Editable for &mut Option<Image> {
     fn edit(self, ui: &mut egui::Ui) {
         if let Some(image) = self {
@@ -244,12 +259,6 @@ impl<'a> Editable for NonNeg<'a> {
     }
 }
 
-impl<'a, const U: usize> Editable for Precise<'a, U> {
-    fn edit(self, ui: &mut egui::Ui) {
-        ui.add(egui::DragValue::new(self.0).min_decimals(U));
-    }
-}
-
 impl Editable for &mut Image {
     fn edit(self, ui: &mut egui::Ui) {
         ui.vertical(|ui| {
@@ -385,25 +394,239 @@ impl Editable for &mut FeedstockMap {
 
 impl Editable for &mut Factor {
     fn edit(self, ui: &mut egui::Ui) {
-        let mut kind: FactorKind = (*self).into();
+        let mut kind = FactorKind::from(&*self);
         ui.vertical(|ui| {
-            ui.add(toggle_enum(&mut kind));
-            if let Factor::Output(output) = self {
-                ui.add(
-                    edit(output)
-                        .label("Output Type")
-                        .help("The output to use for the demand factor.")
-                        .inline(),
-                );
+            ui.add(edit(&mut kind));
+            match self {
+                Factor::Output(output) => {
+                    ui.add(
+                        edit(output)
+                            .label("Output Type")
+                            .help("The output to use for the demand factor.")
+                            .inline(),
+                    );
+                }
+                Factor::ResourcePressure(resource) => {
+                    ui.add(
+                        edit(resource)
+                            .label("Resource")
+                            .help("The resource whose demand relative to what's available is used.")
+                            .inline(),
+                    );
+                }
+                Factor::FeedstockScarcity(feedstock) => {
+                    ui.add(
+                        edit(feedstock)
+                            .label("Feedstock")
+                            .help("The feedstock whose used-up share of its starting reserves is used, from 0 to 1.")
+                            .inline(),
+                    );
+                }
+                Factor::Regions(income) => {
+                    ui.add(
+                        edit(income)
+                            .label("Income Level")
+                            .help("Count the regions at or above this income level.")
+                            .inline(),
+                    );
+                }
+                Factor::Constant(value) => {
+                    ui.add(
+                        edit(value)
+                            .label("Value")
+                            .help("A fixed value to use in the formula.")
+                            .inline(),
+                    );
+                }
+                Factor::Sum(factors) | Factor::Product(factors) => {
+                    let help = if kind == FactorKind::Sum {
+                        "These factors are added together."
+                    } else {
+                        "These factors are multiplied together."
+                    };
+                    ui.add(edit_list(
+                        factors,
+                        "Factors",
+                        Some(help),
+                        |ui| {
+                            if ui.button("Add").clicked() {
+                                Some(Factor::Constant(1.))
+                            } else {
+                                None
+                            }
+                        },
+                        |ui, item| {
+                            ui.add(edit(item));
+                        },
+                    ));
+                }
+                Factor::Inverse(factor) => {
+                    ui.add(
+                        edit(factor.as_mut())
+                            .label("Factor")
+                            .help("One is divided by this factor, e.g. to make the project cheaper as it increases.")
+                            .inline(),
+                    );
+                }
+                Factor::Time
+                | Factor::Income
+                | Factor::Temperature
+                | Factor::SeaLevelRise
+                | Factor::GroupProjects => (),
             }
         });
 
-        if kind != (*self).into() {
+        if kind != FactorKind::from(&*self) {
             *self = kind.into();
         }
     }
 }
 
+impl Editable for &mut Option<ProjectTarget> {
+    fn edit(self, ui: &mut egui::Ui) {
+        ui.vertical(|ui| {
+            let mut enable = self.is_some();
+            ui.add(toggle(&mut enable, "Targeted", "Everywhere"));
+            if enable != self.is_some() {
+                *self = match self {
+                    Some(_) => None,
+                    None => Some(ProjectTarget::Region),
+                };
+            }
+
+            if let Some(target) = self {
+                let mut kind: ProjectTargetKind = (*target).into();
+                ui.add(toggle_enum(&mut kind));
+                match target {
+                    ProjectTarget::Region => {
+                        ui.add(parts::help("The player chooses which region to target."));
+                    }
+                    ProjectTarget::Income(income) => {
+                        ui.add(
+                            edit(income)
+                                .label("Income Level")
+                                .help("Target regions at this income level.")
+                                .inline(),
+                        );
+                    }
+                    ProjectTarget::Latitude(latitude) => {
+                        ui.add(
+                            edit(latitude)
+                                .label("Latitude")
+                                .help("Target regions at this latitude.")
+                                .inline(),
+                        );
+                    }
+                    ProjectTarget::RegionFlag(flag) => {
+                        ui.add(
+                            edit(flag)
+                                .label("Flag")
+                                .help("Target regions with this flag.")
+                                .inline(),
+                        );
+                    }
+                }
+                if kind != (*target).into() {
+                    *target = kind.into();
+                }
+            }
+        });
+    }
+}
+
+impl Editable for &mut ConstructionRisk {
+    fn edit(self, ui: &mut egui::Ui) {
+        input_table(
+            ui,
+            "risk",
+            [
+                share(&mut self.delay)
+                    .label("Delay")
+                    .help("Yearly chance of the year's progress being lost."),
+                share(&mut self.overrun)
+                    .label("Cost Overrun")
+                    .help("Yearly chance of the project becoming more expensive."),
+                share(&mut self.failure)
+                    .label("Failure")
+                    .help("Yearly chance of some of the work done so far having to be redone."),
+            ],
+        );
+    }
+}
+
+impl Editable for &mut Repeal {
+    fn edit(self, ui: &mut egui::Ui) {
+        input_table(
+            ui,
+            "repeal-costs",
+            [
+                edit(&mut self.cost)
+                    .label("Cost")
+                    .help("Political capital it costs to repeal the policy."),
+                edit(&mut self.lock_in).label("Lock-In").help(
+                    "How many years after passing the policy can't be repealed or downgraded.",
+                ),
+            ],
+        );
+        input_table(
+            ui,
+            "repeal-fallout",
+            [
+                edit(&mut self.supporter_backlash)
+                    .label("Supporter Backlash")
+                    .help("How much supporters' relationships drop, on top of the usual change."),
+                edit(&mut self.opposer_approval)
+                    .label("Opposer Approval")
+                    .help("How much opposers' relationships improve, on top of the usual change."),
+                edit(&mut self.outlook)
+                    .label("Outlook")
+                    .help("The change in outlook, usually negative."),
+            ],
+        );
+    }
+}
+
+impl Editable for &mut Option<Upkeep> {
+    fn edit(self, ui: &mut egui::Ui) {
+        ui.vertical(|ui| {
+            let mut enable = self.is_some();
+            ui.add(toggle(&mut enable, "Recurring", "None"));
+            if enable != self.is_some() {
+                *self = match self {
+                    Some(_) => None,
+                    None => Some(Upkeep::PoliticalCapital(5)),
+                };
+            }
+
+            if let Some(upkeep) = self {
+                let mut kind: UpkeepKind = (*upkeep).into();
+                ui.add(toggle_enum(&mut kind));
+                match upkeep {
+                    Upkeep::PoliticalCapital(amount) => {
+                        ui.add(
+                            edit(amount)
+                                .label("Political Capital")
+                                .help("Political capital paid each planning cycle.")
+                                .inline(),
+                        );
+                    }
+                    Upkeep::Research(amount) => {
+                        ui.add(
+                            edit(amount)
+                                .label("Research Points")
+                                .help("Research points paid each planning cycle.")
+                                .inline(),
+                        );
+                    }
+                }
+                if kind != (*upkeep).into() {
+                    *upkeep = kind.into();
+                }
+            }
+        });
+    }
+}
+
 pub struct Input<V: Editable> {
     value: V,
     help: Option<String>,
@@ -517,6 +740,7 @@ macro_rules! enum_dropdown {
 
 enum_dropdown!(Output);
 enum_dropdown!(Resource);
+enum_dropdown!(LandUse);
 enum_dropdown!(Feedstock);
 enum_dropdown!(Byproduct);
 enum_dropdown!(ProcessFeature);
@@ -535,6 +759,11 @@ enum_dropdown!(EffectKind);
 enum_dropdown!(Latitude);
 enum_dropdown!(EventPhase);
 enum_dropdown!(Group);
+enum_dropdown!(NPCAbilityKind);
+enum_dropdown!(PrerequisiteKind);
+enum_dropdown!(FactorKind);
+enum_dropdown!(SynergyInteractionKind);
+enum_dropdown!(NPCDemandKind);
 
 #[derive(strum::Display, strum::EnumIter, PartialEq, Clone, Copy)]
 pub enum CostKind {
@@ -568,6 +797,27 @@ impl Editable for (&mut Vec<Id>, &Collection<NPC>) {
     }
 }
 
+impl Editable for (&mut Vec<Id>, &Collection<Project>) {
+    fn edit(self, ui: &mut egui::Ui) {
+        let (ids, projects) = self;
+        ui.add(edit_list(
+            ids,
+            "Projects",
+            None,
+            |ui| {
+                if ui.button("Add").clicked() {
+                    Some(projects.first().id)
+                } else {
+                    None
+                }
+            },
+            |ui, id| {
+                ui.add(edit((id, projects)));
+            },
+        ));
+    }
+}
+
 impl Editable for (&mut Id, &Collection<Process>) {
     fn edit(self, ui: &mut egui::Ui) {
         let (id, opts) = self;
@@ -901,6 +1151,11 @@ impl Editable
                     WorldVariable::SeaLevelRiseRate => "The annual change in sea level rise, in meters/year.",
                     WorldVariable::Precipitation => "The amount of precipitation, in cm/year.",
                     WorldVariable::PopulationGrowth => "The annual rate of population growth.",
+                    WorldVariable::DemographicTransition => "The change in the speed of the demographic transition.",
+                    WorldVariable::CumulativeEmissions => "Emissions since the start of the game, in Gt CO2eq.",
+                    WorldVariable::OnePointFiveBudget => "The CO2 left in the 1.5C budget, in Gt. Negative once overshot.",
+                    WorldVariable::TwoDegreeBudget => "The CO2 left in the 2C budget, in Gt. Negative once overshot.",
+                    WorldVariable::NetZeroYear => "The projected year of net-zero emissions, if emissions are falling.",
                     _ => "The value to compare against.",
                 };
 
@@ -1293,6 +1548,37 @@ impl Editable
                         .inline(),
                 );
             }
+            Condition::RegionSeceded(seceded) => {
+                ui.add(parts::help("Check if a region has seceded."));
+                ui.add(
+                    toggle(seceded, "Seceded", "Not Seceded")
+                        .label("Seceded")
+                        .help("If the region should have seceded or not.")
+                        .inline(),
+                );
+            }
+            Condition::SecededRegions(comp, value) => {
+                ui.add(parts::help("Compare against the number of regions that have seceded."));
+                parts::two_columns(
+                    ui,
+                    |ui| {
+                        ui.add(
+                            edit(comp)
+                                .label("Comparator")
+                                .help("The comparison operation.")
+                                .inline(),
+                        );
+                    },
+                    |ui| {
+                        ui.add(
+                            edit(value)
+                                .label("Regions")
+                                .help("The value to compare against.")
+                                .inline(),
+                        );
+                    },
+                );
+            }
             Condition::NPCRelationship(id, relation) => {
                 ui.add(parts::help("Check if the relationship status with a particular NPC matches the specified value."));
                 parts::two_columns(
@@ -1409,6 +1695,36 @@ impl Editable
                     },
                 );
             }
+            Condition::LandUse(land_use, comp, value) => {
+                ui.add(parts::help(
+                    "Compare against the percentage of land in a particular use.",
+                ));
+                ui.add(
+                    edit(land_use)
+                        .label("Land Use")
+                        .help("Which land use to check.")
+                        .inline(),
+                );
+                parts::two_columns(
+                    ui,
+                    |ui| {
+                        ui.add(
+                            edit(comp)
+                                .label("Comparator")
+                                .help("The comparison operation.")
+                                .inline(),
+                        );
+                    },
+                    |ui| {
+                        ui.add(
+                            share(value)
+                                .label("Share of Land")
+                                .help("The value to compare against.")
+                                .inline(),
+                        );
+                    },
+                );
+            }
             Condition::WaterStress(comp, value) => {
                 ui.add(parts::help(
                     "Compare against the percentage of water demanded over water available.",
@@ -1523,6 +1839,18 @@ impl Editable
                         "The amount to change the precipitation by, in cm/year."
                     }
                     WorldVariable::PopulationGrowth => "The change in population growth.",
+                    WorldVariable::DemographicTransition => {
+                        "The change in how fast regions move through the demographic transition, e.g. 0.25 for 25% faster."
+                    }
+                    WorldVariable::CumulativeEmissions => {
+                        "The amount to add to cumulative emissions, in Gt CO2."
+                    }
+                    WorldVariable::OnePointFiveBudget | WorldVariable::TwoDegreeBudget => {
+                        "The change to the remaining carbon budgets, in Gt CO2. Both budgets change together."
+                    }
+                    WorldVariable::NetZeroYear => {
+                        "This is projected from the emissions trend and can't be changed directly."
+                    }
                     _ => "The amount to change the variable by.",
                 };
                 parts::two_columns(
@@ -1939,12 +2267,46 @@ impl Editable
                     },
                 );
             }
+            Effect::LocalVariable(var, change) => {
+                ui.add(parts::help("Change a variable of a region. Applies to the event's region or a project's targeted regions, or to every region if there aren't any."));
+                parts::two_columns(
+                    ui,
+                    |ui| {
+                        ui.add(
+                            edit(var)
+                                .label("Variable")
+                                .help("Which variable to change.")
+                                .inline(),
+                        );
+                    },
+                    |ui| {
+                        ui.add(
+                            edit(change)
+                                .label("Change")
+                                .help("The amount to change the variable by.")
+                                .inline(),
+                        );
+                    },
+                );
+            }
             Effect::Migration => {
                 ui.add(parts::help("Triggers a wave of migration across regions."));
             }
             Effect::RegionLeave => {
                 ui.add(parts::help("A region secedes."));
             }
+            Effect::RegionRejoin => {
+                ui.add(parts::help("A seceded region rejoins."));
+            }
+            Effect::Reunification(progress) => {
+                ui.add(parts::help("Advance negotiations for seceded regions to rejoin. Applies to the event's region, or to all seceded regions if there isn't one (e.g. for projects). A region rejoins once negotiations reach 1.0."));
+                ui.add(
+                    edit(progress)
+                        .label("Progress")
+                        .help("How much to advance negotiations by.")
+                        .inline(),
+                );
+            }
             Effect::TerminationShock => {
                 ui.add(parts::help(
 r#"This effect only triggers when it is *unapplied*, in which case it undoes the temperature effect of the "Solar Radiation Management" project."#
@@ -1980,6 +2342,30 @@ r#"This effect only triggers when it is *unapplied*, in which case it undoes the
                     },
                 );
             }
+            Effect::ElectionSwing(id, value) => {
+                ui.add(parts::help(
+                    "Swing an NPC's vote in every election until the effect is removed.",
+                ));
+                parts::two_columns(
+                    ui,
+                    |ui| {
+                        ui.add(
+                            edit((id, npcs))
+                                .label("NPC")
+                                .help("Which NPC's vote is affected.")
+                                .inline(),
+                        );
+                    },
+                    |ui| {
+                        ui.add(
+                            percent(value)
+                                .label("Percent Change")
+                                .help("The percent to change the NPC's vote by.")
+                                .inline(),
+                        );
+                    },
+                );
+            }
             Effect::ModifyProcessByproducts(id, byproduct, value) => {
                 ui.add(parts::help(
                     "Modify the amount of a single byproduct for a single process by a percentage.",
@@ -2215,6 +2601,30 @@ el ranges from [1, 5], where 1 is the lowest demand level and 5 is the highest.
                         .inline(),
                 );
             }
+            Effect::LandUse(land_use, amount) => {
+                ui.add(parts::help(
+                    "Set aside a percentage of land for a particular use. Land set aside can't be used by processes.",
+                ));
+                parts::two_columns(
+                    ui,
+                    |ui| {
+                        ui.add(
+                            edit(land_use)
+                                .label("Land Use")
+                                .help("Which land use to set land aside for.")
+                                .inline(),
+                        );
+                    },
+                    |ui| {
+                        ui.add(
+                            percent(amount)
+                                .label("Percent Change")
+                                .help("The percent of land to add to/remove from this use.")
+                                .inline(),
+                        );
+                    },
+                );
+            }
             Effect::BailOut(amount) => {
                 ui.add(parts::help(
                     "Bail the player out by providing some political capital.",
@@ -2358,3 +2768,480 @@ impl Editable
         ));
     }
 }
+
+impl Editable
+    for (
+        &mut StorylineBranch,
+        &Collection<Process>,
+        &Collection<Project>,
+        &Collection<NPC>,
+    )
+{
+    fn edit(self, ui: &mut egui::Ui) {
+        let (branch, processes, projects, npcs) = self;
+        ui.add(
+            edit(&mut branch.response)
+                .label("Response")
+                .help("If set, this branch is only taken if the dialogue response with this id was selected.")
+                .inline(),
+        );
+        ui.add(
+            edit(&mut branch.next)
+                .label("Next Stage")
+                .help("The stage to move to, by index (starting from 0). If not set, the storyline ends.")
+                .inline(),
+        );
+        ui.add(
+            edit(&mut branch.delay)
+                .label("Delay")
+                .help("How many rolls (of the next event's phase) to wait before the next stage's event triggers.")
+                .inline(),
+        );
+        ui.add(edit((&mut branch.conditions, processes, projects, npcs)));
+    }
+}
+
+impl Editable
+    for (
+        &mut Vec<StorylineBranch>,
+        &Collection<Process>,
+        &Collection<Project>,
+        &Collection<NPC>,
+    )
+{
+    fn edit(self, ui: &mut egui::Ui) {
+        let (list, processes, projects, npcs) = self;
+        ui.add(edit_list(
+            list,
+            "Branches",
+            Some("Branches are checked in their defined order, and the first branch with a matching response and all conditions satisfied is taken. If no branch matches the storyline ends."),
+            |ui| {
+                if ui.button("Add").clicked() {
+                    Some(StorylineBranch {
+                        delay: 1,
+                        ..Default::default()
+                    })
+                } else {
+                    None
+                }
+            },
+            |ui, item| {
+                ui.add(edit((item, processes, projects, npcs)));
+            },
+        ));
+    }
+}
+
+impl Editable
+    for (
+        &mut StorylineStage,
+        &Collection<Process>,
+        &Collection<Project>,
+        &Collection<Event>,
+        &Collection<NPC>,
+    )
+{
+    fn edit(self, ui: &mut egui::Ui) {
+        let (stage, processes, projects, events, npcs) = self;
+        ui.add(
+            edit((&mut stage.event, events))
+                .label("Event")
+                .help("The event for this stage.")
+                .inline(),
+        );
+        ui.add(edit((&mut stage.branches, processes, projects, npcs)));
+    }
+}
+
+impl Editable
+    for (
+        &mut Vec<StorylineStage>,
+        &Collection<Process>,
+        &Collection<Project>,
+        &Collection<Event>,
+        &Collection<NPC>,
+    )
+{
+    fn edit(self, ui: &mut egui::Ui) {
+        let (list, processes, projects, events, npcs) = self;
+        ui.add(edit_list(
+            list,
+            "Stages",
+            Some("The first stage's event triggers like any other event. Events for later stages should be locked so that they only occur as part of the storyline."),
+            |ui| {
+                if ui.button("Add").clicked() {
+                    Some(StorylineStage {
+                        event: events.first().id,
+                        branches: vec![],
+                    })
+                } else {
+                    None
+                }
+            },
+            |ui, item| {
+                ui.add(edit((item, processes, projects, events, npcs)));
+            },
+        ));
+    }
+}
+
+impl Editable for (&mut Prerequisite, &Collection<Project>) {
+    fn edit(self, ui: &mut egui::Ui) {
+        let (prereq, projects) = self;
+        let kind: PrerequisiteKind = prereq.clone().into();
+        ui.label(egui::RichText::new(kind.to_string()).underline());
+        match prereq {
+            Prerequisite::ProjectStatus(id, status) => {
+                parts::two_columns(
+                    ui,
+                    |ui| {
+                        ui.add(
+                            edit((id, projects))
+                                .label("Project")
+                                .help("Which project has to get there first.")
+                                .inline(),
+                        );
+                    },
+                    |ui| {
+                        ui.add(
+                            edit(status)
+                                .label("Status")
+                                .help("The status it has to reach. Active and Finished count as the same.")
+                                .inline(),
+                        );
+                    },
+                );
+            }
+            Prerequisite::ProjectLevel(id, level) => {
+                parts::two_columns(
+                    ui,
+                    |ui| {
+                        ui.add(
+                            edit((id, projects))
+                                .label("Project")
+                                .help("Which project has to get there first.")
+                                .inline(),
+                        );
+                    },
+                    |ui| {
+                        ui.add(
+                            edit(level)
+                                .label("Upgrades")
+                                .help("How many upgrades it needs to have.")
+                                .inline(),
+                        );
+                    },
+                );
+            }
+            Prerequisite::HasFlag(flag) => {
+                ui.add(
+                    edit(flag)
+                        .label("Flag")
+                        .help("Which flag has to be set.")
+                        .inline(),
+                );
+            }
+            Prerequisite::Year(year) => {
+                ui.add(
+                    edit(year)
+                        .label("Year")
+                        .help("The earliest year the project can be unlocked.")
+                        .inline(),
+                );
+            }
+        }
+    }
+}
+
+impl Editable for (&mut Vec<Prerequisite>, &Collection<Project>) {
+    fn edit(self, ui: &mut egui::Ui) {
+        let (list, projects) = self;
+        ui.add(edit_list(
+            list,
+            "Prerequisites",
+            Some("The project starts locked and is unlocked once all of these are met."),
+            |ui| {
+                let mut kind: PrerequisiteKind = ui.memory(|mem| {
+                    mem.data
+                        .get_temp("new-prerequisite".into())
+                        .unwrap_or(PrerequisiteKind::ProjectStatus)
+                });
+
+                let orig = kind;
+                ui.add(edit(&mut kind));
+                if orig != kind {
+                    ui.memory_mut(|mem| mem.data.insert_temp("new-prerequisite".into(), kind));
+                }
+
+                if ui.button("Add").clicked() {
+                    Some(Prerequisite::from_kind(kind, projects.first().id))
+                } else {
+                    None
+                }
+            },
+            |ui, item| {
+                ui.add(edit((item, projects)));
+            },
+        ));
+    }
+}
+
+impl Editable for &mut NPCAbility {
+    fn edit(self, ui: &mut egui::Ui) {
+        let kind: NPCAbilityKind = self.clone().into();
+        ui.label(egui::RichText::new(kind.to_string()).underline());
+        match self {
+            NPCAbility::ProjectCostModifier(group, modifier) => {
+                parts::two_columns(
+                    ui,
+                    |ui| {
+                        ui.add(
+                            edit(group)
+                                .label("Group")
+                                .help("What project group is affected.")
+                                .inline(),
+                        );
+                    },
+                    |ui| {
+                        ui.add(
+                            edit(modifier)
+                                .label("Modifier")
+                                .help("Multiplies the cost of projects in this group, e.g. 0.5 makes them half as expensive.")
+                                .inline(),
+                        );
+                    },
+                );
+            }
+            NPCAbility::RegionOutlookRecovery(income, amount) => {
+                parts::two_columns(
+                    ui,
+                    |ui| {
+                        ui.add(
+                            edit(income)
+                                .label("Income")
+                                .help("What income level is affected.")
+                                .inline(),
+                        );
+                    },
+                    |ui| {
+                        ui.add(
+                            edit(amount)
+                                .label("Recovery")
+                                .help("How much the outlook of these regions recovers each year. Normally this is 0.1.")
+                                .inline(),
+                        );
+                    },
+                );
+            }
+            NPCAbility::ResearchPointDiscount(amount) => {
+                ui.add(
+                    edit(amount)
+                        .label("Discount")
+                        .help("How much political capital is taken off the cost of research points.")
+                        .inline(),
+                );
+            }
+            NPCAbility::PreventOutlookGameOver => {
+                ui.add(parts::help(
+                    "The player won't lose if the world outlook drops below 0.",
+                ));
+            }
+        }
+    }
+}
+
+impl Editable for &mut Vec<NPCAbility> {
+    fn edit(self, ui: &mut egui::Ui) {
+        ui.add(edit_list(
+            self,
+            "Abilities",
+            Some("Abilities are active while the NPC is an ally."),
+            |ui| {
+                let mut kind: NPCAbilityKind = ui.memory(|mem| {
+                    mem.data
+                        .get_temp("new-ability".into())
+                        .unwrap_or(NPCAbilityKind::ProjectCostModifier)
+                });
+
+                let orig = kind;
+                ui.add(edit(&mut kind));
+                if orig != kind {
+                    ui.memory_mut(|mem| mem.data.insert_temp("new-ability".into(), kind));
+                }
+
+                if ui.button("Add").clicked() {
+                    Some(NPCAbility::from_kind(kind))
+                } else {
+                    None
+                }
+            },
+            |ui, item| {
+                ui.add(edit(item));
+            },
+        ));
+    }
+}
+
+impl Editable for (&mut NPCDemand, &Collection<Process>, &Collection<Project>) {
+    fn edit(self, ui: &mut egui::Ui) {
+        let (demand, processes, projects) = self;
+        let kind: NPCDemandKind = demand.clone().into();
+        ui.label(egui::RichText::new(kind.to_string()).underline());
+        match demand {
+            NPCDemand::Project(id, active) => {
+                parts::two_columns(
+                    ui,
+                    |ui| {
+                        ui.add(
+                            edit((id, projects))
+                                .label("Project")
+                                .help("Which project is requested.")
+                                .inline(),
+                        );
+                    },
+                    |ui| {
+                        ui.add(toggle(active, "Active", "Inactive").label("Active").help("If the request is for this project to be implemented (active) or stopped (inactive).").inline());
+                    },
+                );
+            }
+            NPCDemand::Process(id, active) => {
+                parts::two_columns(
+                    ui,
+                    |ui| {
+                        ui.add(
+                            edit((id, processes))
+                                .label("Process")
+                                .help("Which process is requested.")
+                                .inline(),
+                        );
+                    },
+                    |ui| {
+                        ui.add(toggle(active, "Active", "Inactive").label("Active").help("If the request is for this process to be unbanned (active) or banned (inactive).").inline());
+                    },
+                );
+            }
+            NPCDemand::Flag(flag) => {
+                ui.add(
+                    edit(flag)
+                        .label("Flag")
+                        .help("Which flag has to be set.")
+                        .inline(),
+                );
+            }
+            NPCDemand::Protection(amount) => {
+                ui.add(
+                    share(amount)
+                        .label("Protected Land")
+                        .help("The share of land that has to be protected.")
+                        .inline(),
+                );
+            }
+            NPCDemand::Output(output, amount) => {
+                parts::two_columns(
+                    ui,
+                    |ui| {
+                        ui.add(
+                            edit(output)
+                                .label("Output")
+                                .help("Which output has to be produced.")
+                                .inline(),
+                        );
+                    },
+                    |ui| {
+                        ui.add(
+                            share(amount)
+                                .label("Demand Met")
+                                .help("The share of demand for the output that has to be met.")
+                                .inline(),
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+impl Editable
+    for (
+        &mut AgendaItem,
+        &Collection<Process>,
+        &Collection<Project>,
+        &Collection<NPC>,
+    )
+{
+    fn edit(self, ui: &mut egui::Ui) {
+        let (item, processes, projects, npcs) = self;
+        ui.add(edit((&mut item.demand, processes, projects)));
+        parts::two_columns(
+            ui,
+            |ui| {
+                ui.add(
+                    edit(&mut item.bounty)
+                        .label("Reward")
+                        .help("How much political capital is awarded for fulfilling the request.")
+                        .inline(),
+                );
+                ui.add(
+                    edit(&mut item.years)
+                        .label("Years")
+                        .help("How many years the player has to fulfill the request.")
+                        .inline(),
+                );
+            },
+            |ui| {
+                ui.add(
+                    edit(&mut item.penalty)
+                        .label("Penalty")
+                        .help("How much the relationship drops if the request is ignored.")
+                        .inline(),
+                );
+            },
+        );
+        ui.add(edit((&mut item.conditions, processes, projects, npcs)));
+    }
+}
+
+impl Editable
+    for (
+        &mut Vec<AgendaItem>,
+        &Collection<Process>,
+        &Collection<Project>,
+        &Collection<NPC>,
+    )
+{
+    fn edit(self, ui: &mut egui::Ui) {
+        let (list, processes, projects, npcs) = self;
+        ui.add(edit_list(
+            list,
+            "Agenda",
+            Some("The NPC asks for the first of these whose conditions are met and that isn't already done, one request at a time."),
+            |ui| {
+                let mut kind: NPCDemandKind = ui.memory(|mem| {
+                    mem.data
+                        .get_temp("new-demand".into())
+                        .unwrap_or(NPCDemandKind::Protection)
+                });
+
+                let orig = kind;
+                ui.add(edit(&mut kind));
+                if orig != kind {
+                    ui.memory_mut(|mem| mem.data.insert_temp("new-demand".into(), kind));
+                }
+
+                if ui.button("Add").clicked() {
+                    let default_project = projects.first().id;
+                    let default_process = processes.first().id;
+                    Some(AgendaItem {
+                        demand: NPCDemand::from_kind(kind, default_project, default_process),
+                        ..Default::default()
+                    })
+                } else {
+                    None
+                }
+            },
+            |ui, item| {
+                ui.add(edit((item, processes, projects, npcs)));
+            },
+        ));
+    }
+}
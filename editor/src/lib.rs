@@ -9,7 +9,7 @@ use std::sync::{Arc, LazyLock};
 use egui::mutex::Mutex;
 use egui_notify::Toasts;
 use files::FilePicker;
-use hes_engine::{Collection, Event, NPC, Project, World};
+use hes_engine::{Collection, Event, Project, World};
 use strum::{Display, EnumIter, IntoEnumIterator};
 use tabs::*;
 
@@ -25,13 +25,17 @@ enum Tab {
     Processes,
     Projects,
     Events,
+    Storylines,
+    #[strum(to_string = "Tipping Points")]
+    TippingPoints,
+    Synergies,
+    NPCs,
     Help,
 }
 
 pub struct WorldEditor {
     tab: Tab,
     world: World,
-    npcs: Collection<NPC>,
     events: Collection<Event>,
     projects: Collection<Project>,
     file_picker: FilePicker,
@@ -41,7 +45,6 @@ impl Default for WorldEditor {
         let world = World::default();
         Self {
             tab: Tab::Planet,
-            npcs: NPC::load(),
             events: world.events.clone(),
             projects: world.projects.clone(),
             world,
@@ -108,7 +111,7 @@ impl egui::Widget for &mut WorldEditor {
                         }
                         Tab::Processes => {
                             h_center(ui, "main", |ui| {
-                                let resp = processes(ui, &mut self.world.processes, &self.npcs);
+                                let resp = processes(ui, &mut self.world.processes, &self.world.npcs);
                                 request = resp.inner;
                             });
                         }
@@ -121,7 +124,7 @@ impl egui::Widget for &mut WorldEditor {
                                     &self.projects,
                                     &self.world.industries,
                                     &self.world.events,
-                                    &self.npcs,
+                                    &self.world.npcs,
                                 );
                                 if resp.response.changed() {
                                     self.projects = self.world.projects.clone();
@@ -138,7 +141,7 @@ impl egui::Widget for &mut WorldEditor {
                                     &self.world.projects,
                                     &self.world.industries,
                                     &self.events,
-                                    &self.npcs,
+                                    &self.world.npcs,
                                 );
                                 if resp.response.changed() {
                                     self.events = self.world.events.clone();
@@ -146,6 +149,58 @@ impl egui::Widget for &mut WorldEditor {
                                 request = resp.inner;
                             });
                         }
+                        Tab::Storylines => {
+                            h_center(ui, "main", |ui| {
+                                let resp = storylines(
+                                    ui,
+                                    &mut self.world.storylines,
+                                    &self.world.processes,
+                                    &self.world.projects,
+                                    &self.world.events,
+                                    &self.world.npcs,
+                                );
+                                request = resp.inner;
+                            });
+                        }
+                        Tab::TippingPoints => {
+                            h_center(ui, "main", |ui| {
+                                let resp = tipping_points(
+                                    ui,
+                                    &mut self.world.tipping_points,
+                                    &self.world.processes,
+                                    &self.world.projects,
+                                    &self.world.industries,
+                                    &self.world.events,
+                                    &self.world.npcs,
+                                );
+                                request = resp.inner;
+                            });
+                        }
+                        Tab::Synergies => {
+                            h_center(ui, "main", |ui| {
+                                let resp = synergies(
+                                    ui,
+                                    &mut self.world.synergies,
+                                    &self.world.processes,
+                                    &self.world.projects,
+                                    &self.world.industries,
+                                    &self.world.events,
+                                    &self.world.npcs,
+                                );
+                                request = resp.inner;
+                            });
+                        }
+                        Tab::NPCs => {
+                            h_center(ui, "main", |ui| {
+                                let resp = npcs(
+                                    ui,
+                                    &mut self.world.npcs,
+                                    &self.world.processes,
+                                    &self.world.projects,
+                                );
+                                request = resp.inner;
+                            });
+                        }
                         Tab::Help => help(ui),
                     }
 
@@ -165,6 +220,12 @@ impl egui::Widget for &mut WorldEditor {
                                             self.world.events.remove(&id);
                                             self.events = self.world.events.clone();
                                         }
+                                        Tab::Storylines => self.world.storylines.remove(&id),
+                                        Tab::TippingPoints => {
+                                            self.world.tipping_points.remove(&id)
+                                        }
+                                        Tab::Synergies => self.world.synergies.remove(&id),
+                                        Tab::NPCs => self.world.npcs.remove(&id),
                                         _ => {}
                                     }
                                 } else {
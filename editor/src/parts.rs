@@ -40,6 +40,34 @@ impl CreateNew for hes_engine::Event {
         new
     }
 }
+impl CreateNew for hes_engine::NPC {
+    fn create_new() -> Self {
+        let mut new = Self::default();
+        new.name = format!("NPC {}", &new.id.to_string()[..5]);
+        new
+    }
+}
+impl CreateNew for hes_engine::Storyline {
+    fn create_new() -> Self {
+        let mut new = Self::default();
+        new.name = format!("Storyline {}", &new.id.to_string()[..5]);
+        new
+    }
+}
+impl CreateNew for hes_engine::TippingPoint {
+    fn create_new() -> Self {
+        let mut new = Self::default();
+        new.name = format!("Tipping Point {}", &new.id.to_string()[..5]);
+        new
+    }
+}
+impl CreateNew for hes_engine::Synergy {
+    fn create_new() -> Self {
+        let mut new = Self::default();
+        new.name = format!("Synergy {}", &new.id.to_string()[..5]);
+        new
+    }
+}
 
 pub enum Request {
     Delete(Id),
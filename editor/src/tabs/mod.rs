@@ -1,13 +1,21 @@
 mod events;
 mod help;
 mod industries;
+mod npcs;
 mod processes;
 mod projects;
+mod storylines;
+mod synergies;
+mod tipping_points;
 mod world;
 
 pub use events::events;
 pub use help::help;
 pub use industries::industries;
+pub use npcs::npcs;
 pub use processes::processes;
 pub use projects::projects;
+pub use storylines::storylines;
+pub use synergies::synergies;
+pub use tipping_points::tipping_points;
 pub use world::world;
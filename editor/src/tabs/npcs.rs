@@ -0,0 +1,98 @@
+use hes_engine::{Collection, NPC, Process, Project};
+
+use crate::{inputs, parts};
+
+pub fn npcs(
+    ui: &mut egui::Ui,
+    items: &mut Vec<NPC>,
+    processes: &Collection<Process>,
+    projects: &Collection<Project>,
+) -> parts::ListResponse {
+    // Agenda conditions can refer to any of the NPCs,
+    // including the one being edited.
+    let npcs = Collection::from(items.clone());
+    parts::editable_list("npcs", ui, items, |ui, npc| {
+        npc_view(ui, npc, processes, projects, &npcs)
+    })
+}
+
+fn npc_view(
+    ui: &mut egui::Ui,
+    npc: &mut NPC,
+    processes: &Collection<Process>,
+    projects: &Collection<Project>,
+    npcs: &Collection<NPC>,
+) -> egui::Response {
+    ui.vertical(|ui| {
+        ui.add(inputs::heading(&mut npc.name));
+
+        parts::space(ui);
+
+        parts::two_columns(
+            ui,
+            |ui| {
+                ui.add(
+                    inputs::lock(&mut npc.locked)
+                        .label("Locked")
+                        .help("If this NPC is locked at the start.")
+                        .inline(),
+                );
+                ui.add(
+                    inputs::edit(&mut npc.relationship)
+                        .label("Relationship")
+                        .help("The starting relationship with the player. At 5 or more the NPC is an ally, at 1 or less they're a nemesis.")
+                        .inline(),
+                );
+                ui.add(
+                    inputs::edit(&mut npc.support)
+                        .label("Support")
+                        .help("The starting popular support, which determines their share of parliament seats.")
+                        .inline(),
+                );
+                ui.add(
+                    inputs::edit(&mut npc.extra_seats)
+                        .label("Extra Seats")
+                        .help("Additional seats on top of those from popular support.")
+                        .inline(),
+                );
+                ui.add(
+                    inputs::edit(&mut npc.flavor.color)
+                        .label("Color")
+                        .help("Hex color, e.g. #a52a2a.")
+                        .inline(),
+                );
+            },
+            |ui| {
+                ui.add(inputs::edit(&mut npc.abilities));
+            },
+        );
+
+        parts::space(ui);
+
+        ui.add(inputs::edit((&mut npc.agenda, processes, projects, npcs)));
+
+        parts::space(ui);
+
+        ui.add(
+            inputs::textarea(&mut npc.flavor.description)
+                .label("Description")
+                .help("Describe the NPC."),
+        );
+        ui.add(
+            inputs::textarea(&mut npc.flavor.effects)
+                .label("Effects")
+                .help("Describe the NPC's abilities for the player."),
+        );
+        ui.add(
+            inputs::textarea(&mut npc.flavor.likes)
+                .label("Likes")
+                .help("What the NPC likes."),
+        );
+        ui.add(
+            inputs::textarea(&mut npc.flavor.dislikes)
+                .label("Dislikes")
+                .help("What the NPC dislikes."),
+        );
+    })
+    .response
+}
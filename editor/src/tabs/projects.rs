@@ -41,7 +41,7 @@ fn project_view(
                 inputs::lock(&mut project.locked)
                 .label("Locked")
                 .help(
-                    "If this project is locked at the start.",
+                    "If this project is locked at the start. Projects with prerequisites are always locked until they're met.",
                 ).inline(),
             );
         }, |ui| {
@@ -57,6 +57,26 @@ fn project_view(
 
             parts::space(ui);
 
+            ui.add(inputs::edit(&mut project.upkeep).label("Maintenance").help("A recurring cost paid every planning cycle while the project is online. If the player can't pay, the project stalls and its effects are suspended until they can; if it goes unpaid for too long it's halted.").inline());
+
+            parts::space(ui);
+
+            ui.add(inputs::edit(&mut project.target).label("Target").help("If set, the project's regional effects only apply to the targeted regions, and its cost scales with their share of the population.").inline());
+
+            parts::space(ui);
+
+            if project.kind == ProjectType::Policy {
+                ui.add(inputs::edit(&mut project.repeal).label("Repeal").help("The political fallout of repealing the policy. Only a full repeal carries it, but the lock-in also prevents downgrades.").inline());
+
+                parts::space(ui);
+            }
+
+            if project.kind != ProjectType::Policy {
+                ui.add(inputs::edit(&mut project.risk).label("Construction Risk").help("The yearly chances of setbacks while the project is being built. These are made more likely by labor sabotage, unrest in the regions it targets, and factions opposing it.").inline());
+
+                parts::space(ui);
+            }
+
             if project.kind == ProjectType::Initiative {
                 ui.add(inputs::toggle(&mut project.gradual, "Gradual", "On Completion").label("Activation").help("Does this project have to be 100% finished before the effects occur, or do they develop as the project is developed?").inline());
 
@@ -118,6 +138,11 @@ fn project_view(
         parts::space(ui);
         parts::space(ui);
 
+        ui.add(inputs::edit((&mut project.prerequisites, projects)));
+
+        parts::space(ui);
+        parts::space(ui);
+
         ui.add(inputs::edit((
                     &mut project.effects,
                     processes,
@@ -0,0 +1,110 @@
+use egui::Color32;
+use hes_engine::*;
+
+use crate::{inputs, parts};
+
+pub fn storylines(
+    ui: &mut egui::Ui,
+    items: &mut Vec<Storyline>,
+    processes: &Collection<Process>,
+    projects: &Collection<Project>,
+    events: &Collection<Event>,
+    npcs: &Collection<NPC>,
+) -> parts::ListResponse {
+    parts::editable_list("storylines", ui, items, |ui, item| {
+        storyline_view(ui, item, processes, projects, events, npcs)
+    })
+}
+
+fn storyline_view(
+    ui: &mut egui::Ui,
+    storyline: &mut Storyline,
+    processes: &Collection<Process>,
+    projects: &Collection<Project>,
+    events: &Collection<Event>,
+    npcs: &Collection<NPC>,
+) -> egui::Response {
+    egui::Frame::NONE
+        .show(ui, |ui| {
+            ui.add(inputs::heading(&mut storyline.name));
+
+            parts::space(ui);
+
+            ui.add(inputs::edit((
+                &mut storyline.stages,
+                processes,
+                projects,
+                events,
+                npcs,
+            )));
+
+            parts::space(ui);
+
+            ui.label("Overview");
+            ui.add(parts::help("How the stages connect to each other."));
+            chain(ui, storyline, events);
+
+            parts::space(ui);
+
+            ui.add(
+                inputs::textarea(&mut storyline.notes)
+                    .label("Notes")
+                    .help("Optional notes"),
+            );
+        })
+        .response
+}
+
+/// Summarize the stages and where each branch leads.
+fn chain(ui: &mut egui::Ui, storyline: &Storyline, events: &Collection<Event>) {
+    let event_name = |id: &Id| {
+        events
+            .try_get(id)
+            .map(|ev| ev.name.as_str())
+            .unwrap_or("(missing event)")
+    };
+
+    let unreachable = storyline.unreachable_stages();
+    for (i, stage) in storyline.stages.iter().enumerate() {
+        let label = format!("{i}. {}", event_name(&stage.event));
+        if unreachable.contains(&i) {
+            ui.colored_label(Color32::from_gray(128), format!("{label} (unreachable)"));
+        } else {
+            ui.label(label);
+        }
+
+        ui.indent(i, |ui| {
+            for branch in &stage.branches {
+                let mut reqs = vec![];
+                if let Some(id) = branch.response {
+                    reqs.push(format!("response {id}"));
+                }
+                if !branch.conditions.is_empty() {
+                    reqs.push(format!("{} condition(s)", branch.conditions.len()));
+                }
+                let reqs = if reqs.is_empty() {
+                    "always".to_string()
+                } else {
+                    reqs.join(", ")
+                };
+                let dest = match branch.next {
+                    Some(next) => match storyline.stages.get(next) {
+                        Some(stage) => format!(
+                            "{next}. {} (after {})",
+                            event_name(&stage.event),
+                            branch.delay
+                        ),
+                        None => format!("{next}. (missing stage)"),
+                    },
+                    None => "End".to_string(),
+                };
+                ui.label(format!("↳ {reqs} → {dest}"));
+            }
+            if stage.branches.iter().all(|branch| {
+                branch.response.is_some() || !branch.conditions.is_empty()
+            }) {
+                ui.colored_label(Color32::from_gray(128), "↳ otherwise → End");
+            }
+        });
+    }
+}
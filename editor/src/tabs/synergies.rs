@@ -0,0 +1,98 @@
+use hes_engine::*;
+
+use crate::{inputs, parts};
+
+pub fn synergies(
+    ui: &mut egui::Ui,
+    items: &mut Vec<Synergy>,
+    processes: &Collection<Process>,
+    projects: &Collection<Project>,
+    industries: &Collection<Industry>,
+    events: &Collection<Event>,
+    npcs: &Collection<NPC>,
+) -> parts::ListResponse {
+    parts::editable_list("synergies", ui, items, |ui, item| {
+        synergy_view(ui, item, processes, projects, industries, events, npcs)
+    })
+}
+
+fn synergy_view(
+    ui: &mut egui::Ui,
+    synergy: &mut Synergy,
+    processes: &Collection<Process>,
+    projects: &Collection<Project>,
+    industries: &Collection<Industry>,
+    events: &Collection<Event>,
+    npcs: &Collection<NPC>,
+) -> egui::Response {
+    egui::Frame::NONE
+        .show(ui, |ui| {
+            ui.add(inputs::heading(&mut synergy.name));
+
+            parts::space(ui);
+
+            ui.add(parts::help(
+                "This is active while all of these projects are online.",
+            ));
+            ui.add(inputs::edit((&mut synergy.projects, projects)));
+
+            parts::space(ui);
+
+            let mut kind: SynergyInteractionKind = (&synergy.interaction).into();
+            ui.add(
+                inputs::edit(&mut kind)
+                    .label("Interaction")
+                    .help("What happens while all the projects are online.")
+                    .inline(),
+            );
+            if kind != (&synergy.interaction).into() {
+                synergy.interaction = SynergyInteraction::from_kind(kind, projects.first().id);
+            }
+
+            parts::space(ui);
+
+            match &mut synergy.interaction {
+                SynergyInteraction::Effects(effects) => {
+                    ui.add(parts::help("Effects added while all the projects are online."));
+                    ui.add(inputs::edit((
+                        effects,
+                        processes,
+                        projects,
+                        industries,
+                        events,
+                        npcs,
+                    )));
+                }
+                SynergyInteraction::Scale(id, scale) => {
+                    parts::two_columns(
+                        ui,
+                        |ui| {
+                            ui.add(
+                                inputs::edit((id, projects))
+                                    .label("Project")
+                                    .help("The project whose effects are scaled. It also has to be online.")
+                                    .inline(),
+                            );
+                        },
+                        |ui| {
+                            ui.add(
+                                inputs::edit(scale)
+                                    .label("Scale")
+                                    .help("Multiplies the project's effects, e.g. 0.5 halves them and 2 doubles them.")
+                                    .inline(),
+                            );
+                        },
+                    );
+                }
+            }
+
+            parts::space(ui);
+
+            ui.add(
+                inputs::textarea(&mut synergy.notes)
+                    .label("Notes")
+                    .help("Optional notes"),
+            );
+        })
+        .response
+}
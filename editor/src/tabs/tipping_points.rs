@@ -0,0 +1,116 @@
+use hes_engine::*;
+
+use crate::{inputs, parts};
+
+pub fn tipping_points(
+    ui: &mut egui::Ui,
+    items: &mut Vec<TippingPoint>,
+    processes: &Collection<Process>,
+    projects: &Collection<Project>,
+    industries: &Collection<Industry>,
+    events: &Collection<Event>,
+    npcs: &Collection<NPC>,
+) -> parts::ListResponse {
+    parts::editable_list("tipping-points", ui, items, |ui, item| {
+        tipping_point_view(ui, item, processes, projects, industries, events, npcs)
+    })
+}
+
+fn tipping_point_view(
+    ui: &mut egui::Ui,
+    point: &mut TippingPoint,
+    processes: &Collection<Process>,
+    projects: &Collection<Project>,
+    industries: &Collection<Industry>,
+    events: &Collection<Event>,
+    npcs: &Collection<NPC>,
+) -> egui::Response {
+    egui::Frame::NONE
+        .show(ui, |ui| {
+            ui.add(inputs::heading(&mut point.name));
+
+            parts::space(ui);
+
+            parts::two_columns(
+                ui,
+                |ui| {
+                    ui.add(inputs::edit(&mut point.image));
+                },
+                |ui| {
+                    ui.add(
+                        inputs::edit(&mut point.threshold)
+                            .label("Threshold")
+                            .help("The global temperature anomaly (C) past which this is at risk of tipping.")
+                            .inline(),
+                    );
+
+                    parts::space(ui);
+
+                    ui.add(
+                        inputs::edit(&mut point.years)
+                            .label("Years")
+                            .help("How many consecutive years the temperature has to stay past the threshold for this to tip.")
+                            .inline(),
+                    );
+
+                    parts::space(ui);
+
+                    let mut has_event = point.event.is_some();
+                    ui.add(
+                        inputs::toggle(&mut has_event, "Triggers Event", "No Event")
+                            .label("Event")
+                            .help("An event to trigger when this tips.")
+                            .inline(),
+                    );
+                    if has_event != point.event.is_some() {
+                        point.event = if has_event {
+                            events.iter().next().map(|ev| ev.id)
+                        } else {
+                            None
+                        };
+                    }
+                    if let Some(id) = &mut point.event {
+                        ui.add(inputs::edit((id, events)));
+                    }
+                },
+            );
+
+            parts::space(ui);
+
+            ui.add(
+                inputs::textarea(&mut point.description)
+                    .label("Description")
+                    .help("Shown to the player when this tips."),
+            );
+
+            parts::space(ui);
+
+            ui.add(parts::help(
+                "Other conditions that must also hold for a year to count towards tipping.",
+            ));
+            ui.add(inputs::edit((&mut point.conditions, processes, projects, npcs)));
+
+            parts::space(ui);
+
+            ui.add(parts::help(
+                "Feedbacks applied when this tips. These are never reversed.",
+            ));
+            ui.add(inputs::edit((
+                &mut point.effects,
+                processes,
+                projects,
+                industries,
+                events,
+                npcs,
+            )));
+
+            parts::space(ui);
+
+            ui.add(
+                inputs::textarea(&mut point.notes)
+                    .label("Notes")
+                    .help("Optional notes"),
+            );
+        })
+        .response
+}
@@ -56,6 +56,17 @@ pub fn world(ui: &mut egui::Ui, world: &mut World) {
                     )
                     .inline(),
                 );
+
+                parts::space(ui);
+
+                ui.add(
+                    inputs::edit(&mut world.election_interval)
+                    .label("Election Interval")
+                    .help(
+                        "How many years there are between elections, or zero for no elections.",
+                    )
+                    .inline(),
+                );
             },
         );
 
@@ -85,53 +96,6 @@ pub fn world(ui: &mut egui::Ui, world: &mut World) {
         );
     });
 
-    parts::frame().show(ui, |ui| {
-        ui.set_width(parts::SECTION_WIDTH);
-
-        ui.heading("Annual Population Growth Coefficients");
-        ui.add(parts::help("The coefficients for cubic annual population growth model, with one for each income level."));
-
-        parts::space(ui);
-
-        ui.style_mut().spacing.interact_size.x = 145.; // Min DragValue size
-        TableBuilder::new(ui)
-            .id_salt("pop-coefs")
-            .columns(Column::auto(), 5)
-            .header(parts::ROW_HEIGHT, |mut header| {
-                header.col(|_| {});
-                header.col(|ui| {
-                    ui.label("β₀");
-                });
-                header.col(|ui| {
-                    ui.label("β₁");
-                });
-                header.col(|ui| {
-                    ui.label("β₂");
-                });
-                header.col(|ui| {
-                    ui.label("β₃");
-                });
-            })
-            .body(|mut body| {
-                for (i, label) in
-                    ["Low", "Lower-Middle", "Upper-Middle", "High"]
-                        .iter()
-                        .enumerate()
-                {
-                    body.row(parts::ROW_HEIGHT, |mut row| {
-                        row.col(|ui| {
-                            ui.label(*label);
-                        });
-                        for coef in &mut world.income_pop_coefs[i] {
-                            row.col(|ui| {
-                                ui.add(inputs::precise_float::<16>(coef));
-                            });
-                        }
-                    });
-                }
-            });
-    });
-
     parts::frame().show(ui, |ui| {
         ui.set_width(parts::SECTION_WIDTH);
 
@@ -211,7 +175,7 @@ pub fn world(ui: &mut egui::Ui, world: &mut World) {
         ui.style_mut().spacing.interact_size.x = 80.; // Min DragValue size
         TableBuilder::new(ui)
             .id_salt("regions")
-            .columns(Column::auto(), 4)
+            .columns(Column::auto(), 6)
             .vscroll(false)
             .header(parts::ROW_HEIGHT, |mut header| {
                 header.col(|_| {});
@@ -224,6 +188,12 @@ pub fn world(ui: &mut egui::Ui, world: &mut World) {
                 header.col(|ui| {
                     ui.label("Income Level").on_hover_text("The region's starting income level.");
                 });
+                header.col(|ui| {
+                    ui.label("Transition").on_hover_text("The region's starting progress through the demographic transition, from 0.0 (high birth and death rates) to 1.0 (low birth and death rates).");
+                });
+                header.col(|ui| {
+                    ui.label("Coastal").on_hover_text("The share of the region's population living along the coast, from 0.0 to 1.0. Coastal populations lower the region's habitability as the sea level rises.");
+                });
             })
         .body(|mut body| {
             for region in world.regions.iter_mut()
@@ -241,6 +211,13 @@ pub fn world(ui: &mut egui::Ui, world: &mut World) {
                     row.col(|ui| {
                         ui.add(inputs::edit(&mut region.income));
                     });
+                    row.col(|ui| {
+                        let transition = region.transition();
+                        ui.add(inputs::share(region.transition.get_or_insert(transition)));
+                    });
+                    row.col(|ui| {
+                        ui.add(inputs::share(&mut region.coastal_population));
+                    });
                 });
             }
         });
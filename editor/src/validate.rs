@@ -1,16 +1,28 @@
 use std::collections::HashSet;
 
-use hes_engine::{Condition, Effect, Id, World, flavor::DialogueNext};
+use hes_engine::{
+    Condition,
+    Effect,
+    Id,
+    SynergyInteraction,
+    World,
+    flavor::{Dialogue, DialogueNext},
+};
 
 // Errors:
 // - Effect refers to entity that doesn't exist.
 // - Condition refers to entity that doesn't exist.
+// - Dialogue has missing, unreachable, or inescapable lines.
+// - Project prerequisites loop back on themselves.
+// - Synergy refers to a project that doesn't exist.
+// - NPC agenda refers to an entity that doesn't exist.
 
 struct IdTracker {
     projects: Vec<Id>,
     processes: Vec<Id>,
     industries: Vec<Id>,
     events: Vec<Id>,
+    npcs: Vec<Id>,
 }
 impl IdTracker {
     fn new(world: &World) -> Self {
@@ -19,6 +31,7 @@ impl IdTracker {
             processes: world.processes.iter().map(|item| item.id).collect(),
             industries: world.industries.iter().map(|item| item.id).collect(),
             events: world.events.iter().map(|item| item.id).collect(),
+            npcs: world.npcs.iter().map(|item| item.id).collect(),
         }
     }
 
@@ -31,6 +44,8 @@ impl IdTracker {
             self.industries.contains(&id)
         } else if let Some(id) = effect.event_id() {
             self.events.contains(&id)
+        } else if let Some(id) = effect.npc_id() {
+            self.npcs.contains(&id)
         } else {
             true
         }
@@ -41,6 +56,8 @@ impl IdTracker {
             self.projects.contains(&id)
         } else if let Some(id) = condition.process_id() {
             self.processes.contains(&id)
+        } else if let Some(id) = condition.npc_id() {
+            self.npcs.contains(&id)
         } else {
             true
         }
@@ -58,15 +75,31 @@ pub fn find_references(id: Id, world: &World) -> Vec<String> {
             effect.process_id(),
             effect.industry_id(),
             effect.event_id(),
+            effect.npc_id(),
         ]
         .into_iter()
         .any(|id_| id_ == Some(id))
     };
 
-    let check_condition =
-        move |cond: &Condition| cond.project_id() == Some(id) || cond.process_id() == Some(id);
+    let check_condition = move |cond: &Condition| {
+        [cond.project_id(), cond.process_id(), cond.npc_id()]
+            .into_iter()
+            .any(|id_| id_ == Some(id))
+    };
+
+    for item in world.processes.iter() {
+        if item.supporters.contains(&id) || item.opposers.contains(&id) {
+            referenced_by.insert(item.name.clone());
+        }
+    }
 
     for item in world.projects.iter() {
+        if item.supporters.contains(&id) || item.opposers.contains(&id) {
+            referenced_by.insert(item.name.clone());
+        }
+        if item.prerequisites.iter().any(|prereq| prereq.project_id() == Some(id)) {
+            referenced_by.insert(item.name.clone());
+        }
         for effect in &item.effects {
             if check_effect(effect) {
                 referenced_by.insert(item.name.clone());
@@ -144,6 +177,64 @@ pub fn find_references(id: Id, world: &World) -> Vec<String> {
         }
     }
 
+    for item in world.storylines.iter() {
+        for stage in &item.stages {
+            if stage.event == id {
+                referenced_by.insert(item.name.clone());
+            }
+            for branch in &stage.branches {
+                for cond in &branch.conditions {
+                    if check_condition(cond) {
+                        referenced_by.insert(item.name.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    for item in world.tipping_points.iter() {
+        if item.event == Some(id) {
+            referenced_by.insert(item.name.clone());
+        }
+        for cond in &item.conditions {
+            if check_condition(cond) {
+                referenced_by.insert(item.name.clone());
+            }
+        }
+        for effect in &item.effects {
+            if check_effect(effect) {
+                referenced_by.insert(item.name.clone());
+            }
+        }
+    }
+
+    for item in world.synergies.iter() {
+        if item.involves(&id) {
+            referenced_by.insert(item.name.clone());
+        }
+        if let SynergyInteraction::Effects(effects) = &item.interaction {
+            for effect in effects {
+                if check_effect(effect) {
+                    referenced_by.insert(item.name.clone());
+                }
+            }
+        }
+    }
+
+    for item in world.npcs.iter() {
+        for agenda in &item.agenda {
+            let demand = &agenda.demand;
+            if demand.project_id() == Some(id) || demand.process_id() == Some(id) {
+                referenced_by.insert(item.name.clone());
+            }
+            for cond in &agenda.conditions {
+                if check_condition(cond) {
+                    referenced_by.insert(item.name.clone());
+                }
+            }
+        }
+    }
+
     referenced_by.into_iter().collect()
 }
 
@@ -152,6 +243,14 @@ pub fn validate(world: &World) -> Vec<String> {
     let tracker = IdTracker::new(world);
 
     for item in world.projects.iter() {
+        for prereq in &item.prerequisites {
+            if prereq.project_id().is_some_and(|id| !tracker.projects.contains(&id)) {
+                errors.push(format!(
+                    "Project prerequisites of {:?} refers to a non-existent entity.",
+                    item.name
+                ));
+            }
+        }
         for effect in &item.effects {
             if !tracker.check_effect(effect) {
                 errors.push(format!(
@@ -190,6 +289,17 @@ pub fn validate(world: &World) -> Vec<String> {
         }
     }
 
+    if let Some(cycle) = world.project_graph().find_cycle() {
+        let names: Vec<&str> = cycle
+            .iter()
+            .map(|id| world.projects.try_get(id).map_or("?", |item| item.name.as_str()))
+            .collect();
+        errors.push(format!(
+            "Projects {:?} are each other's prerequisites, so they can never be unlocked.",
+            names.join(" -> ")
+        ));
+    }
+
     for item in world.events.iter() {
         for effect in &item.effects {
             if !tracker.check_effect(effect) {
@@ -210,5 +320,337 @@ pub fn validate(world: &World) -> Vec<String> {
             }
         }
     }
+
+    for (name, dialogue) in dialogues(world) {
+        check_dialogue(&name, dialogue, &tracker, &mut errors, &mut vec![]);
+    }
+
+    for item in world.storylines.iter() {
+        if item.stages.is_empty() {
+            errors.push(format!("Storyline {:?} has no stages.", item.name));
+        }
+        for (i, stage) in item.stages.iter().enumerate() {
+            if !tracker.events.contains(&stage.event) {
+                errors.push(format!(
+                    "Storyline {:?} stage {i} refers to a non-existent event.",
+                    item.name
+                ));
+            }
+            for branch in &stage.branches {
+                if branch.next.is_some_and(|next| next >= item.stages.len()) {
+                    errors.push(format!(
+                        "Storyline {:?} stage {i} branches to a non-existent stage.",
+                        item.name
+                    ));
+                }
+                for cond in &branch.conditions {
+                    if !tracker.check_condition(cond) {
+                        errors.push(format!(
+                            "Storyline {:?} stage {i} conditions refers to a non-existent entity.",
+                            item.name
+                        ));
+                    }
+                }
+            }
+        }
+        for i in item.unreachable_stages() {
+            errors.push(format!(
+                "Storyline {:?} stage {i} can never be reached.",
+                item.name
+            ));
+        }
+    }
+
+    for item in world.tipping_points.iter() {
+        if item.event.is_some_and(|id| !tracker.events.contains(&id)) {
+            errors.push(format!(
+                "Tipping point {:?} refers to a non-existent event.",
+                item.name
+            ));
+        }
+        for cond in &item.conditions {
+            if !tracker.check_condition(cond) {
+                errors.push(format!(
+                    "Tipping point {:?} conditions refers to a non-existent entity.",
+                    item.name
+                ));
+            }
+        }
+        for effect in &item.effects {
+            if !tracker.check_effect(effect) {
+                errors.push(format!(
+                    "Tipping point {:?} refers to a non-existent entity.",
+                    item.name
+                ));
+            }
+        }
+    }
+
+    for item in world.synergies.iter() {
+        if item.projects.is_empty() {
+            errors.push(format!("Synergy {:?} has no projects.", item.name));
+        }
+        if item
+            .project_ids()
+            .iter()
+            .any(|id| !tracker.projects.contains(id))
+        {
+            errors.push(format!(
+                "Synergy {:?} refers to a non-existent project.",
+                item.name
+            ));
+        }
+        if let SynergyInteraction::Effects(effects) = &item.interaction {
+            for effect in effects {
+                if !tracker.check_effect(effect) {
+                    errors.push(format!(
+                        "Synergy {:?} effects refers to a non-existent entity.",
+                        item.name
+                    ));
+                }
+            }
+        }
+    }
+
+    for item in world.npcs.iter() {
+        for agenda in &item.agenda {
+            let missing_project = agenda
+                .demand
+                .project_id()
+                .is_some_and(|id| !tracker.projects.contains(&id));
+            let missing_process = agenda
+                .demand
+                .process_id()
+                .is_some_and(|id| !tracker.processes.contains(&id));
+            if missing_project || missing_process {
+                errors.push(format!(
+                    "NPC agenda of {:?} refers to a non-existent entity.",
+                    item.name
+                ));
+            }
+            for cond in &agenda.conditions {
+                if !tracker.check_condition(cond) {
+                    errors.push(format!(
+                        "NPC agenda conditions of {:?} refers to a non-existent entity.",
+                        item.name
+                    ));
+                }
+            }
+        }
+    }
     errors
 }
+
+/// Issues that don't prevent saving but
+/// should probably be looked at.
+pub fn warnings(world: &World) -> Vec<String> {
+    let mut warnings = vec![];
+    let tracker = IdTracker::new(world);
+    for (name, dialogue) in dialogues(world) {
+        check_dialogue(&name, dialogue, &tracker, &mut vec![], &mut warnings);
+    }
+    warnings
+}
+
+fn dialogues(world: &World) -> impl Iterator<Item = (String, &Dialogue)> {
+    let projects = world.projects.iter().flat_map(|item| {
+        item.flavor
+            .outcomes
+            .iter()
+            .enumerate()
+            .map(move |(i, dialogue)| (format!("Project {:?} outcome {i}", item.name), dialogue))
+    });
+    let events = world
+        .events
+        .iter()
+        .map(|item| (format!("Event {:?}", item.name), &item.flavor.dialogue));
+    projects.chain(events)
+}
+
+/// Walk the dialogue graph, where lines are
+/// referred to by their position in `lines`.
+/// Lines that can never be reached are only warned about,
+/// as they're harmless.
+fn check_dialogue(
+    name: &str,
+    dialogue: &Dialogue,
+    tracker: &IdTracker,
+    errors: &mut Vec<String>,
+    warnings: &mut Vec<String>,
+) {
+    let n = dialogue.lines.len();
+    if n == 0 {
+        return;
+    }
+    if dialogue.root >= n {
+        errors.push(format!("{name} dialogue root line doesn't exist."));
+        return;
+    }
+
+    // Outgoing line indices for each line and
+    // whether or not the dialogue can end at that line.
+    let mut edges: Vec<Vec<usize>> = vec![vec![]; n];
+    let mut ends = vec![false; n];
+    for (i, line) in dialogue.lines.iter().enumerate() {
+        match &line.next {
+            None => ends[i] = true,
+            Some(DialogueNext::Line { id }) => {
+                if *id < n {
+                    edges[i].push(*id);
+                } else {
+                    errors.push(format!(
+                        "{name} dialogue line {i} leads to a non-existent line {id}."
+                    ));
+                }
+            }
+            Some(DialogueNext::Responses(resps)) => {
+                for resp in resps {
+                    match resp.next_line {
+                        None => ends[i] = true,
+                        Some(id) if id < n => edges[i].push(id),
+                        Some(id) => errors.push(format!(
+                            "{name} dialogue line {i} has a response leading to a non-existent line {id}."
+                        )),
+                    }
+                }
+            }
+        }
+    }
+
+    let mut reachable = vec![false; n];
+    let mut frontier = vec![dialogue.root];
+    while let Some(i) = frontier.pop() {
+        if !reachable[i] {
+            reachable[i] = true;
+            frontier.extend(&edges[i]);
+        }
+    }
+
+    // Propagate backwards from the lines
+    // where the dialogue can end.
+    let mut can_exit = ends;
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for i in 0..n {
+            if !can_exit[i] && edges[i].iter().any(|j| can_exit[*j]) {
+                can_exit[i] = true;
+                changed = true;
+            }
+        }
+    }
+
+    let mut line_ids = HashSet::new();
+    for (i, line) in dialogue.lines.iter().enumerate() {
+        for variant in &line.variants {
+            if !variant.conditions.iter().all(|cond| tracker.check_condition(cond)) {
+                errors.push(format!(
+                    "{name} dialogue line {i} conditions refers to a non-existent entity."
+                ));
+            }
+        }
+        if let Some(DialogueNext::Responses(resps)) = &line.next {
+            for resp in resps {
+                if !resp.effects.iter().all(|effect| tracker.check_effect(effect)) {
+                    errors.push(format!(
+                        "{name} dialogue line {i} response effects refers to a non-existent entity."
+                    ));
+                }
+                if !resp.conditions.iter().all(|cond| tracker.check_condition(cond)) {
+                    errors.push(format!(
+                        "{name} dialogue line {i} response conditions refers to a non-existent entity."
+                    ));
+                }
+            }
+        }
+
+        if !reachable[i] {
+            // Blank lines are often just leftovers.
+            if !line.text.trim().is_empty() {
+                warnings.push(format!("{name} dialogue line {i} can never be reached."));
+            }
+            continue;
+        }
+        if !can_exit[i] {
+            errors.push(format!(
+                "{name} dialogue line {i} is stuck in a loop with no way to end the dialogue."
+            ));
+        }
+        if !line_ids.insert(line.id) {
+            errors.push(format!("{name} dialogue has duplicate line id {}.", line.id));
+        }
+        if let Some(DialogueNext::Responses(resps)) = &line.next {
+            let mut resp_ids = HashSet::new();
+            for resp in resps {
+                if !resp_ids.insert(resp.id) {
+                    errors.push(format!(
+                        "{name} dialogue line {i} has duplicate response id {}.",
+                        resp.id
+                    ));
+                }
+                if resp.text.trim().is_empty() {
+                    errors.push(format!("{name} dialogue line {i} has a response with no text."));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hes_engine::flavor::{DialogueLine, Speaker};
+
+    use super::*;
+
+    fn line(id: usize, next: Option<usize>) -> DialogueLine {
+        DialogueLine {
+            id,
+            next: next.map(|id| DialogueNext::Line { id }),
+            speaker: Speaker::Gossy,
+            text: format!("Line {id}"),
+            variants: vec![],
+        }
+    }
+
+    fn check(lines: Vec<DialogueLine>) -> (Vec<String>, Vec<String>) {
+        let tracker = IdTracker {
+            projects: vec![],
+            processes: vec![],
+            industries: vec![],
+            events: vec![],
+            npcs: vec![],
+        };
+        let dialogue = Dialogue { root: 0, lines };
+        let mut errors = vec![];
+        let mut warnings = vec![];
+        check_dialogue("Test", &dialogue, &tracker, &mut errors, &mut warnings);
+        (errors, warnings)
+    }
+
+    #[test]
+    fn test_missing_dialogue_line() {
+        let (errors, _) = check(vec![line(0, Some(1)), line(1, Some(5))]);
+        let error = "Test dialogue line 1 leads to a non-existent line 5.";
+        assert!(errors.iter().any(|e| e == error));
+    }
+
+    #[test]
+    fn test_unreachable_dialogue_line() {
+        let (errors, warnings) = check(vec![line(0, None), line(1, None)]);
+        assert!(errors.is_empty());
+        assert_eq!(warnings, vec!["Test dialogue line 1 can never be reached."]);
+    }
+
+    #[test]
+    fn test_inescapable_dialogue_loop() {
+        let (errors, warnings) = check(vec![line(0, Some(1)), line(1, Some(0))]);
+        assert!(warnings.is_empty());
+        assert_eq!(
+            errors,
+            vec![
+                "Test dialogue line 0 is stuck in a loop with no way to end the dialogue.",
+                "Test dialogue line 1 is stuck in a loop with no way to end the dialogue.",
+            ]
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumDiscriminants, EnumIter, EnumString, IntoStaticStr};
+
+use crate::{
+    Id,
+    events::{Condition, Flag},
+    kinds::Output,
+    projects::Status,
+    state::State,
+};
+
+/// How many years an NPC waits after one of
+/// its requests is closed before making another.
+pub const REQUEST_COOLDOWN: usize = 5;
+
+/// Something an NPC can ask the player for.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumIter, EnumString, IntoStaticStr, Display))]
+#[strum_discriminants(name(DemandKind))]
+pub enum Demand {
+    /// Implement (true) or stop (false) a project.
+    Project(Id, bool),
+
+    /// Unban (true) or ban (false) a process.
+    Process(Id, bool),
+
+    /// Have this flag set, e.g. by a policy.
+    Flag(Flag),
+
+    /// Protect at least this share of land.
+    Protection(f32),
+
+    /// Meet at least this share of demand for an output.
+    Output(Output, f32),
+}
+
+impl Demand {
+    pub fn from_kind(kind: DemandKind, default_project: Id, default_process: Id) -> Self {
+        match kind {
+            DemandKind::Project => Self::Project(default_project, true),
+            DemandKind::Process => Self::Process(default_process, false),
+            DemandKind::Flag => Self::Flag(Flag::Vegan),
+            DemandKind::Protection => Self::Protection(0.3),
+            DemandKind::Output => Self::Output(Output::PlantCalories, 1.),
+        }
+    }
+
+    pub fn project_id(&self) -> Option<Id> {
+        match self {
+            Demand::Project(id, _) => Some(*id),
+            _ => None,
+        }
+    }
+
+    pub fn process_id(&self) -> Option<Id> {
+        match self {
+            Demand::Process(id, _) => Some(*id),
+            _ => None,
+        }
+    }
+
+    pub fn is_met(&self, state: &State) -> bool {
+        match self {
+            Demand::Project(id, active) => {
+                let status = state.world.projects[id].status;
+                if *active {
+                    matches!(status, Status::Active | Status::Finished)
+                } else {
+                    matches!(status, Status::Inactive | Status::Halted)
+                }
+            }
+            Demand::Process(id, active) => {
+                let process = &state.world.processes[id];
+                if *active {
+                    process.is_promoted()
+                } else {
+                    process.is_banned()
+                }
+            }
+            Demand::Flag(flag) => state.flags.contains(flag),
+            Demand::Protection(share) => state.land.reserved.protected >= *share,
+            Demand::Output(output, share) => {
+                let demand = state.output_demand.of(*output);
+                demand <= 0. || state.produced.of(*output) / demand >= *share
+            }
+        }
+    }
+}
+
+/// Something an NPC will ask for
+/// once all its conditions are met.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AgendaItem {
+    pub conditions: Vec<Condition>,
+    pub demand: Demand,
+
+    /// Political capital for fulfilling the request.
+    pub bounty: usize,
+
+    /// How many years the player has to fulfill it.
+    pub years: usize,
+
+    /// How much the relationship drops
+    /// if the request is ignored.
+    pub penalty: f32,
+}
+impl Default for AgendaItem {
+    fn default() -> Self {
+        Self {
+            conditions: vec![],
+            demand: Demand::Protection(0.3),
+            bounty: 10,
+            years: 10,
+            penalty: 0.5,
+        }
+    }
+}
+
+/// A request an NPC has made from its agenda.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AgendaRequest {
+    pub npc_id: Id,
+    pub demand: Demand,
+    pub bounty: usize,
+    pub penalty: f32,
+
+    /// The request expires at the end of this year.
+    pub deadline: usize,
+}
+
+impl AgendaRequest {
+    pub fn years_left(&self, year: usize) -> usize {
+        self.deadline.saturating_sub(year)
+    }
+
+    pub fn is_expired(&self, year: usize) -> bool {
+        year >= self.deadline
+    }
+}
+
+/// Requests that were closed when they were checked.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ClosedRequests {
+    pub fulfilled: Vec<AgendaRequest>,
+    pub expired: Vec<AgendaRequest>,
+}
+
+/// The requests NPCs have made from their agendas.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Agendas {
+    pub requests: Vec<AgendaRequest>,
+
+    /// The year each NPC's last request was closed.
+    pub last_closed: BTreeMap<Id, usize>,
+}
+
+impl Agendas {
+    pub fn request(&self, npc_id: &Id) -> Option<&AgendaRequest> {
+        self.requests.iter().find(|req| req.npc_id == *npc_id)
+    }
+
+    /// NPCs make one request at a time and
+    /// wait a while after each one is closed.
+    pub fn can_request(&self, npc_id: &Id, year: usize) -> bool {
+        self.request(npc_id).is_none()
+            && self
+                .last_closed
+                .get(npc_id)
+                .is_none_or(|closed| year >= closed + REQUEST_COOLDOWN)
+    }
+
+    pub fn add(&mut self, npc_id: Id, item: &AgendaItem, year: usize) -> AgendaRequest {
+        let request = AgendaRequest {
+            npc_id,
+            demand: item.demand.clone(),
+            bounty: item.bounty,
+            penalty: item.penalty,
+            deadline: year + item.years,
+        };
+        self.requests.push(request.clone());
+        request
+    }
+
+    pub fn close(&mut self, npc_id: Id, year: usize) {
+        self.requests.retain(|req| req.npc_id != npc_id);
+        self.last_closed.insert(npc_id, year);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        events::{Comparator, WorldVariable},
+        npcs::RELATIONSHIP_CHANGE_AMOUNT,
+    };
+
+    #[test]
+    fn test_agenda_requests() {
+        let mut state = State::default();
+        let year = state.world.year;
+        let ids: Vec<Id> = state.npcs.unlocked().take(2).map(|npc| npc.id).collect();
+        let (asker, waiter) = (ids[0], ids[1]);
+        for npc in state.npcs.iter_mut() {
+            npc.agenda.clear();
+        }
+        state.npcs[&asker].agenda = vec![AgendaItem {
+            demand: Demand::Flag(Flag::Vegan),
+            bounty: 15,
+            years: 5,
+            penalty: 1.,
+            ..Default::default()
+        }];
+        state.npcs[&waiter].agenda = vec![AgendaItem {
+            conditions: vec![Condition::WorldVariable(
+                WorldVariable::Year,
+                Comparator::GreaterEqual,
+                (year + 100) as f32,
+            )],
+            demand: Demand::Protection(1.),
+            ..Default::default()
+        }];
+
+        // Only NPCs whose conditions are met make requests.
+        let requests = state.update_agendas();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].npc_id, asker);
+        assert_eq!(requests[0].deadline, year + 5);
+
+        // One request at a time.
+        assert!(state.update_agendas().is_empty());
+
+        // Ignored requests expire and cost the relationship.
+        let relationship = state.npcs[&asker].relationship;
+        state.world.year = year + 5;
+        let closed = state.check_agenda_requests();
+        assert!(closed.fulfilled.is_empty());
+        assert_eq!(closed.expired.len(), 1);
+        assert_eq!(state.npcs[&asker].relationship, relationship - 1.);
+        assert!(state.agendas.requests.is_empty());
+
+        // NPCs wait a while before asking again.
+        assert!(state.update_agendas().is_empty());
+        state.world.year += REQUEST_COOLDOWN;
+        assert_eq!(state.update_agendas().len(), 1);
+
+        state.flags.push(Flag::Vegan);
+        let closed = state.check_agenda_requests();
+        assert_eq!(closed.fulfilled.len(), 1);
+        assert_eq!(closed.fulfilled[0].bounty, 15);
+        assert_eq!(
+            state.npcs[&asker].relationship,
+            relationship - 1. + RELATIONSHIP_CHANGE_AMOUNT
+        );
+
+        // Nothing is asked for that's already done.
+        state.world.year += REQUEST_COOLDOWN;
+        assert!(state.update_agendas().is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+
+/// Remaining CO2 budgets (Gt) at the start of the game
+/// for a 50% chance of keeping warming to 1.5°C and 2°C,
+/// roughly following the IPCC AR6 estimates.
+pub const BUDGET_1_5C: f32 = 500.;
+pub const BUDGET_2C: f32 = 1350.;
+
+/// How many years of emissions the
+/// net-zero projection is based on.
+const TREND_YEARS: usize = 5;
+
+/// Emissions accumulated since the start of the game,
+/// tracked against the remaining carbon budgets.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CarbonBudget {
+    /// Cumulative CO2 emissions, in Gt.
+    pub co2: f32,
+
+    /// Cumulative CO2eq emissions, in Gt.
+    pub co2eq: f32,
+
+    /// The last few years of annual
+    /// CO2eq emissions (Gt), oldest first.
+    pub recent: Vec<f32>,
+}
+
+impl CarbonBudget {
+    /// Add a year of emissions, in Gt.
+    pub fn add_year(&mut self, co2: f32, co2eq: f32) {
+        self.co2 += co2;
+        self.co2eq += co2eq;
+        self.recent.push(co2eq);
+        if self.recent.len() > TREND_YEARS {
+            self.recent.remove(0);
+        }
+    }
+
+    /// Directly add CO2 emissions (Gt) outside
+    /// of the yearly accounting, e.g. from events.
+    pub fn add(&mut self, co2: f32) {
+        self.co2 += co2;
+        self.co2eq += co2;
+    }
+
+    /// CO2 (Gt) left before the 1.5°C budget is spent.
+    /// Negative once it's overshot.
+    pub fn remaining_1_5c(&self) -> f32 {
+        BUDGET_1_5C - self.co2
+    }
+
+    /// CO2 (Gt) left before the 2°C budget is spent.
+    /// Negative once it's overshot.
+    pub fn remaining_2c(&self) -> f32 {
+        BUDGET_2C - self.co2
+    }
+
+    /// The year net-zero emissions will be reached if
+    /// the recent trend continues, or `None` if
+    /// emissions aren't falling.
+    pub fn net_zero_year(&self, year: usize) -> Option<usize> {
+        let latest = *self.recent.last()?;
+        if latest <= 0. {
+            return Some(year);
+        }
+        let years = self.recent.len() - 1;
+        if years == 0 {
+            return None;
+        }
+        let slope = (latest - self.recent[0]) / years as f32;
+        if slope >= 0. {
+            None
+        } else {
+            Some(year + (latest / -slope).ceil() as usize)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_carbon_budget() {
+        let mut budget = CarbonBudget::default();
+        assert_eq!(budget.net_zero_year(2022), None);
+
+        // Steady emissions never reach net-zero.
+        for _ in 0..10 {
+            budget.add_year(40., 50.);
+        }
+        assert_eq!(budget.co2, 400.);
+        assert_eq!(budget.co2eq, 500.);
+        assert_eq!(budget.recent.len(), TREND_YEARS);
+        assert_eq!(budget.remaining_1_5c(), 100.);
+        assert_eq!(budget.net_zero_year(2032), None);
+
+        // Falling by 5Gt a year from 30Gt.
+        for co2eq in [50., 45., 40., 35., 30.] {
+            budget.add_year(co2eq, co2eq);
+        }
+        assert!(budget.remaining_1_5c() < 0.);
+        assert!(budget.remaining_2c() > 0.);
+        assert_eq!(budget.net_zero_year(2037), Some(2043));
+
+        budget.add_year(-1., -1.);
+        assert_eq!(budget.net_zero_year(2038), Some(2038));
+    }
+}
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoStaticStr};
+
+/// How much a cost overrun adds to the project's cost.
+pub const OVERRUN_COST: f32 = 0.1;
+
+/// How much of a project's total progress
+/// is lost when part of it fails.
+pub const FAILURE_LOSS: f32 = 0.25;
+
+/// The yearly chances of a project running into
+/// trouble while it's being built, from 0 to 1.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Risk {
+    /// The year's progress is lost.
+    pub delay: f32,
+
+    /// The project becomes more expensive.
+    pub overrun: f32,
+
+    /// Some of the work done so far has to be redone.
+    pub failure: f32,
+}
+
+impl Risk {
+    /// Scale all the chances, e.g. because
+    /// conditions on the ground are worse.
+    pub fn scale(&self, multiplier: f32) -> Risk {
+        Risk {
+            delay: (self.delay * multiplier).clamp(0., 1.),
+            overrun: (self.overrun * multiplier).clamp(0., 1.),
+            failure: (self.failure * multiplier).clamp(0., 1.),
+        }
+    }
+
+    /// The chance of any setback in a given year.
+    pub fn any(&self) -> f32 {
+        1. - (1. - self.delay) * (1. - self.overrun) * (1. - self.failure)
+    }
+
+    /// Roll for this year's setback, if any.
+    /// Failures are checked first as they're the worst.
+    pub fn roll(&self) -> Option<Setback> {
+        if fastrand::f32() < self.failure {
+            Some(Setback::Failure)
+        } else if fastrand::f32() < self.delay {
+            Some(Setback::Delay)
+        } else if fastrand::f32() < self.overrun {
+            Some(Setback::Overrun)
+        } else {
+            None
+        }
+    }
+
+    /// A likely upper bound on how long something
+    /// that would take `years` without any setbacks
+    /// will actually take.
+    pub fn likely_years(&self, years: usize) -> usize {
+        let years = years as f32;
+
+        // Delays and failures both cost the year's progress,
+        // failures also lose some of what was already built.
+        let lost = self.delay + self.failure * (1. + FAILURE_LOSS * years);
+
+        // Overruns compound over the rest of the build.
+        let overrun = 1. + self.overrun * OVERRUN_COST * years / 2.;
+        (years * (1. + lost) * overrun).ceil() as usize
+    }
+}
+
+/// Something that went wrong while building a project.
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, EnumIter, IntoStaticStr, Display,
+)]
+pub enum Setback {
+    Delay,
+    Overrun,
+    Failure,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_risk() {
+        let risk = Risk::default();
+        assert_eq!(risk.any(), 0.);
+        assert_eq!(risk.roll(), None);
+        assert_eq!(risk.likely_years(5), 5);
+
+        let risk = Risk {
+            delay: 0.2,
+            overrun: 0.1,
+            failure: 0.05,
+        };
+        assert!(risk.likely_years(5) > 5);
+        assert!(risk.scale(2.).likely_years(5) > risk.likely_years(5));
+        assert_eq!(risk.scale(100.).any(), 1.);
+
+        let certain = Risk {
+            delay: 1.,
+            ..Default::default()
+        };
+        assert_eq!(certain.roll(), Some(Setback::Delay));
+    }
+}
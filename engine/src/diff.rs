@@ -129,10 +129,14 @@ impl<T: DiffLabel + PartialEq> Diff for Vec<T> {
 impl Diff for State {
     fn diff(&self, other: &Self) -> Vec<Change> {
         let mut changes = diffs!(self, other, {
-            political_capital, protected_land });
+            political_capital, land.reserved.protected });
         changes.extend(diff_methods!(self, other, {
             emissions.as_gtco2eq
         }));
+        changes.push(Change::Nested(
+            "land_use".into(),
+            self.land.allocation().diff(&other.land.allocation()),
+        ));
         changes.push(Change::Nested(
             "production".into(),
             self.produced
@@ -282,7 +286,7 @@ mod tests {
         let mut state_b = State::default();
         state_b.emissions.co2 += 1200000000000.;
         state_b.political_capital = 500;
-        state_b.protected_land *= 2.;
+        state_b.land.reserved.protected *= 2.;
         state_b.flags.push(Flag::EcosystemModeling);
         state_b.world.projects.by_idx_mut(5).status =
             Status::Finished;
@@ -0,0 +1,181 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoStaticStr};
+
+use crate::{IconEvent, regions::Region};
+
+/// Habitability lost per point of disaster severity,
+/// recovered over the following years.
+const DAMAGE_PER_SEVERITY: f32 = 0.1;
+
+/// Share of the population killed or
+/// displaced per point of disaster severity.
+const MORTALITY_PER_SEVERITY: f32 = 0.0002;
+
+/// Outlook lost per point of disaster severity.
+const OUTLOOK_PER_SEVERITY: f32 = 0.1;
+
+/// Resilience can reduce damage by at most this share.
+const MAX_RESILIENCE: f32 = 0.8;
+
+/// Share of a region's production disrupted
+/// per point of lingering disaster damage.
+const DISRUPTION_PER_DAMAGE: f32 = 0.05;
+const MAX_DISRUPTION: f32 = 0.5;
+
+/// The climate hazard behind a disaster, which
+/// determines how exposed a region is to it.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+    EnumIter,
+    IntoStaticStr,
+    Display,
+)]
+pub enum Hazard {
+    Heat,
+    Drought,
+    Fire,
+    Flood,
+}
+
+impl Hazard {
+    /// Classify a disaster by its icon.
+    /// Disasters that aren't climate-driven,
+    /// e.g. attacks or blackouts, have no hazard.
+    pub fn of(event: &IconEvent) -> Option<Hazard> {
+        let kind = event.icon.split("__").next().unwrap_or_default();
+        match kind {
+            "heatwave" => Some(Hazard::Heat),
+            "crop_failure" => Some(Hazard::Drought),
+            "wildfires" => Some(Hazard::Fire),
+            "flood" | "hurricane" => Some(Hazard::Flood),
+            _ => None,
+        }
+    }
+
+    /// How exposed the region is to this hazard,
+    /// from 0 to 1, given its current climate.
+    pub fn exposure(&self, region: &Region) -> f32 {
+        let risks = &region.climate_risks;
+        match self {
+            Hazard::Heat => risks.heatwave,
+            Hazard::Drought => risks.drought,
+            Hazard::Fire => risks.heatwave.max(risks.drought),
+            Hazard::Flood => risks.flood,
+        }
+    }
+
+    /// How well the region is protected from this hazard,
+    /// e.g. sea walls protect against floods but not heatwaves.
+    pub fn resilience(&self, region: &Region) -> f32 {
+        region.resilience.get(self).copied().unwrap_or(0.)
+    }
+}
+
+/// What disasters did to a region.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DisasterImpact {
+    /// Habitability lost.
+    pub damage: f32,
+
+    /// People killed or displaced for good.
+    pub deaths: f32,
+
+    /// Outlook lost.
+    pub outlook: f32,
+}
+
+impl DisasterImpact {
+    /// Disasters hit harder where the climate makes
+    /// a region more exposed and softer where it has
+    /// invested in resilience.
+    pub fn of(event: &IconEvent, region: &Region) -> Self {
+        let hazard = Hazard::of(event);
+        let exposure = hazard.map_or(0., |hazard| hazard.exposure(region));
+        let protection = hazard
+            .map_or(0., |hazard| hazard.resilience(region))
+            .clamp(0., MAX_RESILIENCE);
+        let severity = event.intensity as f32 * (1. + exposure) * (1. - protection);
+        DisasterImpact {
+            damage: severity * DAMAGE_PER_SEVERITY,
+            deaths: region.population * severity * MORTALITY_PER_SEVERITY,
+            outlook: severity * OUTLOOK_PER_SEVERITY,
+        }
+    }
+}
+
+impl std::ops::AddAssign for DisasterImpact {
+    fn add_assign(&mut self, rhs: Self) {
+        self.damage += rhs.damage;
+        self.deaths += rhs.deaths;
+        self.outlook += rhs.outlook;
+    }
+}
+
+/// Share of a region's production disrupted
+/// by the disaster damage it hasn't yet recovered from.
+pub fn disruption(region: &Region) -> f32 {
+    (region.disaster_damage * DISRUPTION_PER_DAMAGE).min(MAX_DISRUPTION)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn gen_event(icon: &str, intensity: usize) -> IconEvent {
+        IconEvent {
+            name: icon.into(),
+            icon: icon.into(),
+            intensity,
+        }
+    }
+
+    #[test]
+    fn test_disaster_impact() {
+        let mut region = Region {
+            population: 1e6,
+            ..Default::default()
+        };
+        let flood = gen_event("flood__2", 2);
+        let base = DisasterImpact::of(&flood, &region);
+        assert!(base.damage > 0.);
+        assert!(base.deaths > 0.);
+        assert!(base.outlook > 0.);
+
+        // More exposed regions are hit harder,
+        // but only by the matching hazards.
+        region.climate_risks.flood = 1.;
+        let exposed = DisasterImpact::of(&flood, &region);
+        assert_eq!(exposed.damage, base.damage * 2.);
+        let heat = DisasterImpact::of(&gen_event("heatwave__2", 2), &region);
+        assert_eq!(heat.damage, base.damage);
+
+        // Resilience softens the blow, up to a point,
+        // but only against the hazard it was built for.
+        region.resilience.insert(Hazard::Flood, 0.5);
+        let protected = DisasterImpact::of(&flood, &region);
+        assert_eq!(protected.damage, exposed.damage * 0.5);
+        let heat = DisasterImpact::of(&gen_event("heatwave__2", 2), &region);
+        assert_eq!(heat.damage, base.damage);
+        region.resilience.insert(Hazard::Flood, 2.);
+        let protected = DisasterImpact::of(&flood, &region);
+        assert!(protected.damage > 0.);
+
+        // Production recovers with the region.
+        region.damage(protected.damage);
+        let disrupted = disruption(&region);
+        assert!(disrupted > 0.);
+        for _ in 0..100 {
+            region.recover();
+        }
+        assert_eq!(disruption(&region), 0.);
+    }
+}
@@ -0,0 +1,314 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Collection, Id, npcs::NPC};
+
+/// How many years there are between elections,
+/// unless the world says otherwise.
+pub const DEFAULT_INTERVAL: usize = 10;
+
+/// Political capital each campaign action costs.
+pub const CAMPAIGN_COST: usize = 10;
+
+/// How many times the player can campaign
+/// for a faction before each election.
+pub const MAX_CAMPAIGNS: usize = 3;
+
+/// How much each campaign action grows
+/// a faction's share of the vote.
+const CAMPAIGN_SWING: f32 = 0.1;
+
+/// How much a fully content (or fully discontent)
+/// world swings the vote towards (or away from)
+/// the player's allies.
+const OUTLOOK_SWING: f32 = 0.25;
+
+/// The most of its vote a faction can lose in one election.
+const MAX_LOSS: f32 = 0.75;
+
+pub fn default_interval() -> usize {
+    DEFAULT_INTERVAL
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FactionResult {
+    pub npc_id: Id,
+    pub seats_before: f32,
+    pub seats: f32,
+}
+
+impl FactionResult {
+    /// The change in share of seats.
+    pub fn change(&self) -> f32 {
+        self.seats - self.seats_before
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ElectionResult {
+    pub year: usize,
+    pub factions: Vec<FactionResult>,
+}
+
+/// The election cycle: campaigning for
+/// the next election and the results of the last one.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Elections {
+    /// The year of the last election, or of the start of the game.
+    /// Saves from before elections don't have it, so it's set
+    /// the first time elections are checked after loading.
+    pub last: Option<usize>,
+
+    /// How many times the player has campaigned
+    /// for each faction this election.
+    pub campaigns: BTreeMap<Id, usize>,
+
+    /// Swings in each faction's vote from event and project
+    /// effects, as a share of its current vote. Unlike campaigns
+    /// these carry over until the effects are removed.
+    pub swings: BTreeMap<Id, f32>,
+
+    /// The results of the last election, if there's been one.
+    pub results: Option<ElectionResult>,
+}
+
+impl Elections {
+    pub fn new(year: usize) -> Self {
+        Self {
+            last: Some(year),
+            ..Default::default()
+        }
+    }
+
+    /// The year of the last election, counting
+    /// from this year if it isn't known yet.
+    fn last(&self, year: usize) -> usize {
+        self.last.unwrap_or(year)
+    }
+
+    /// If there's an election this year.
+    /// An interval of zero means there are no elections.
+    pub fn is_due(&self, year: usize, interval: usize) -> bool {
+        interval > 0 && year >= self.last(year) + interval
+    }
+
+    /// The year of the next election, if there is one.
+    pub fn next(&self, year: usize, interval: usize) -> Option<usize> {
+        if interval > 0 {
+            Some(self.last(year) + interval)
+        } else {
+            None
+        }
+    }
+
+    /// Pass over an election that couldn't be held,
+    /// e.g. because parliament is suspended,
+    /// so the next one is a full interval away.
+    pub fn skip(&mut self, year: usize) {
+        self.last = Some(year);
+        self.campaigns.clear();
+    }
+
+    pub fn campaigns(&self, npc_id: &Id) -> usize {
+        self.campaigns.get(npc_id).copied().unwrap_or(0)
+    }
+
+    pub fn can_campaign(&self, npc_id: &Id) -> bool {
+        self.campaigns(npc_id) < MAX_CAMPAIGNS
+    }
+
+    pub fn campaign(&mut self, npc_id: &Id) {
+        *self.campaigns.entry(*npc_id).or_default() += 1;
+    }
+
+    /// How much a faction's vote will grow or shrink,
+    /// as a share of its current vote.
+    ///
+    /// The player's allies are judged on how content
+    /// the world is (the `mood`, from -1 to 1), and their
+    /// opponents are judged the other way around.
+    pub fn swing(&self, npc: &NPC, mood: f32) -> f32 {
+        let events = self.swings.get(&npc.id).copied().unwrap_or(0.);
+        (self.lasting_swing(npc, mood) + events).max(-MAX_LOSS)
+    }
+
+    /// The part of the swing that carries over into the
+    /// faction's support, i.e. everything but effect swings,
+    /// which last as long as their effects and so would
+    /// otherwise compound from one election to the next.
+    fn lasting_swing(&self, npc: &NPC, mood: f32) -> f32 {
+        // A neutral relationship is 3, an ally is 5.
+        let alignment = ((npc.relationship - 3.) / 2.).clamp(-1., 1.);
+        let campaigns = self.campaigns(&npc.id) as f32 * CAMPAIGN_SWING;
+        (alignment * mood * OUTLOOK_SWING + campaigns).max(-MAX_LOSS)
+    }
+
+    /// Hold an election, which reallocates the seats by each
+    /// faction's swing and shifts its support for the next one.
+    pub fn hold(&mut self, npcs: &mut Collection<NPC>, mood: f32, year: usize) -> ElectionResult {
+        let swings: Vec<(Id, f32, f32)> = npcs
+            .unlocked()
+            .map(|npc| (npc.id, self.swing(npc, mood), self.lasting_swing(npc, mood)))
+            .collect();
+        let mut seats_before = BTreeMap::new();
+        let mut votes = BTreeMap::new();
+        for (id, swing, lasting) in &swings {
+            let npc = &mut npcs[id];
+            seats_before.insert(*id, npc.seats);
+            votes.insert(*id, npc.support * (1. + swing));
+            npc.support *= 1. + lasting;
+        }
+
+        let total_votes: f32 = votes.values().sum();
+        if total_votes > 0. {
+            for (id, vote) in &votes {
+                npcs[id].seats = vote / total_votes;
+            }
+        }
+
+        let result = ElectionResult {
+            year,
+            factions: seats_before
+                .into_iter()
+                .map(|(npc_id, seats_before)| FactionResult {
+                    npc_id,
+                    seats_before,
+                    seats: npcs[&npc_id].seats,
+                })
+                .collect(),
+        };
+        self.last = Some(year);
+        self.campaigns.clear();
+        self.results = Some(result.clone());
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        events::{Effect, Flag},
+        ledger::{Currency, Source},
+        state::State,
+    };
+
+    #[test]
+    fn test_election() {
+        let mut state = State::default();
+        let year = state.world.year;
+        let interval = state.world.election_interval;
+        assert!(!state.elections.is_due(year, interval));
+        assert!(state.elections.is_due(year + interval, interval));
+        assert!(!state.elections.is_due(year + interval, 0));
+
+        let ids: Vec<Id> = state.npcs.unlocked().take(3).map(|npc| npc.id).collect();
+        let (ally, nemesis, other) = (ids[0], ids[1], ids[2]);
+        state.npcs[&ally].relationship = 5.;
+        state.npcs[&nemesis].relationship = 1.;
+
+        // Campaigning costs political capital, up to a limit.
+        state.political_capital = (CAMPAIGN_COST * (MAX_CAMPAIGNS + 1)) as isize;
+        for _ in 0..MAX_CAMPAIGNS {
+            assert!(state.campaign(&other));
+        }
+        assert!(!state.campaign(&other));
+        assert_eq!(state.political_capital, CAMPAIGN_COST as isize);
+        assert_eq!(
+            state.ledger.breakdown(Currency::PoliticalCapital)[&Source::Campaign].expenses,
+            (CAMPAIGN_COST * MAX_CAMPAIGNS) as isize
+        );
+
+        // A content world rewards the player's allies.
+        let mood = state.election_mood();
+        assert!(mood > 0.);
+        let seats: Vec<f32> = ids.iter().map(|id| state.npcs[id].seats).collect();
+        state.world.year = year + interval;
+        let result = state.hold_election();
+        assert_eq!(result.year, year + interval);
+        assert!(state.npcs[&ally].seats > seats[0]);
+        assert!(state.npcs[&nemesis].seats < seats[1]);
+        assert!(state.npcs[&other].seats > seats[2]);
+        let total: f32 = result.factions.iter().map(|f| f.seats).sum();
+        assert!((total - 1.).abs() < 1e-4);
+
+        // Campaigns are only for the one election.
+        assert_eq!(state.elections.campaigns(&other), 0);
+        assert!(!state.elections.is_due(state.world.year, interval));
+    }
+
+    #[test]
+    fn test_election_swings() {
+        let mut state = State::default();
+        let npc_id = state.npcs.unlocked().next().unwrap().id;
+        let effect = Effect::ElectionSwing(npc_id, 0.2);
+        effect.apply(&mut state, None);
+
+        // Effect swings last across elections,
+        // until the effect is removed.
+        state.hold_election();
+        assert_eq!(state.elections.swings[&npc_id], 0.2);
+        effect.unapply(&mut state, None);
+        assert_eq!(state.elections.swings[&npc_id], 0.);
+    }
+
+    #[test]
+    fn test_election_swings_dont_compound() {
+        let mut state = State::default();
+        let npc_id = state.npcs.unlocked().next().unwrap().id;
+
+        // With a neutral world only the event swing counts.
+        for region in state.world.regions.iter_mut() {
+            region.outlook = 0.;
+        }
+        assert_eq!(state.election_mood(), 0.);
+        let seats = state.npcs[&npc_id].seats;
+        Effect::ElectionSwing(npc_id, 0.2).apply(&mut state, None);
+
+        state.hold_election();
+        let first = state.npcs[&npc_id].seats;
+        assert!(first > seats);
+        state.hold_election();
+        let second = state.npcs[&npc_id].seats;
+        assert!((second - first).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_skipped_election() {
+        let mut state = State::default();
+        let year = state.world.year;
+        let interval = state.world.election_interval;
+
+        // Elections are put off while parliament is suspended,
+        // and the next is a full interval after the skipped one.
+        state.flags.push(Flag::ParliamentSuspended);
+        state.world.year = year + interval;
+        state.finish_cycle();
+        assert!(state.elections.results.is_none());
+        assert_eq!(
+            state.elections.next(state.world.year, interval),
+            Some(year + interval * 2)
+        );
+
+        state.flags.clear();
+        state.finish_cycle();
+        assert!(state.elections.results.is_none());
+    }
+
+    #[test]
+    fn test_elections_in_old_saves() {
+        let mut state = State::default();
+        let interval = state.world.election_interval;
+        state.world.year += interval * 3;
+
+        // Older saves have no elections, and shouldn't
+        // hold one as soon as they're loaded.
+        let mut json = serde_json::to_value(&state).unwrap();
+        json.as_object_mut().unwrap().remove("elections");
+        let mut state: State = serde_json::from_value(json).unwrap();
+        state.finish_cycle();
+        assert!(state.elections.results.is_none());
+        assert_eq!(state.elections.last, Some(state.world.year));
+    }
+}
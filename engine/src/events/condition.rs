@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use super::{Flag, LocalVariable, PlayerVariable, RegionFlag, WorldVariable};
 use crate::{
     Id,
-    kinds::{Feedstock, Output, Resource},
+    kinds::{Feedstock, LandUse, Output, Resource},
     npcs::NPCRelation,
     production::ProcessFeature,
     projects::{Group, Status as ProjectStatus},
@@ -36,12 +36,15 @@ pub enum Condition {
     ActiveProjectUpgrades(Id, Comparator, usize),
     RunsPlayed(Comparator, usize),
     RegionFlag(RegionFlag),
+    RegionSeceded(bool),
+    SecededRegions(Comparator, usize),
     NPCRelationship(Id, NPCRelation),
     FeedstockYears(Feedstock, Comparator, f32),
     HasFlag(Flag),
     WithoutFlag(Flag),
     HeavyProjects(Comparator, usize),
     ProtectLand(Comparator, f32),
+    LandUse(LandUse, Comparator, f32),
     WaterStress(Comparator, f32),
 }
 
@@ -76,12 +79,15 @@ impl Condition {
             }
             ConditionKind::RunsPlayed => Self::RunsPlayed(comp, 1),
             ConditionKind::RegionFlag => Self::RegionFlag(RegionFlag::Protests),
+            ConditionKind::RegionSeceded => Self::RegionSeceded(true),
+            ConditionKind::SecededRegions => Self::SecededRegions(comp, 1),
             ConditionKind::NPCRelationship => Self::NPCRelationship(default_npc, NPCRelation::Ally),
             ConditionKind::FeedstockYears => Self::FeedstockYears(Feedstock::Coal, comp, 0.),
             ConditionKind::HasFlag => Self::HasFlag(Flag::Vegan),
             ConditionKind::WithoutFlag => Self::WithoutFlag(Flag::Vegan),
             ConditionKind::HeavyProjects => Self::HeavyProjects(comp, 1),
             ConditionKind::ProtectLand => Self::ProtectLand(comp, 0.),
+            ConditionKind::LandUse => Self::LandUse(LandUse::Protected, comp, 0.),
             ConditionKind::WaterStress => Self::WaterStress(comp, 0.),
         }
     }
@@ -101,12 +107,22 @@ impl Condition {
             _ => None,
         }
     }
+
+    pub fn npc_id(&self) -> Option<Id> {
+        match self {
+            Condition::NPCRelationship(id, ..) => Some(*id),
+            _ => None,
+        }
+    }
 }
 
 impl Condition {
     /// If this condition has any regional conditions.
     pub fn is_regional(&self) -> bool {
-        matches!(self, Self::LocalVariable(..) | Self::RegionFlag(..))
+        matches!(
+            self,
+            Self::LocalVariable(..) | Self::RegionFlag(..) | Self::RegionSeceded(..)
+        )
     }
 
     pub fn eval(&self, state: &State, region_id: Option<Id>) -> bool {
@@ -117,7 +133,18 @@ impl Condition {
                     let val = match var {
                         LocalVariable::Population => region.population,
                         LocalVariable::Outlook => region.outlook,
-                        LocalVariable::Habitability => region.habitability(),
+                        LocalVariable::Habitability => {
+                            region.habitability(state.world.sea_level_rise)
+                        }
+                        LocalVariable::HeatwaveRisk => region.climate_risks.heatwave,
+                        LocalVariable::DroughtRisk => region.climate_risks.drought,
+                        LocalVariable::FloodRisk => region.climate_risks.flood,
+                        LocalVariable::HeatResilience
+                        | LocalVariable::DroughtResilience
+                        | LocalVariable::FireResilience
+                        | LocalVariable::FloodResilience => {
+                            var.hazard().map_or(0., |hazard| hazard.resilience(region))
+                        }
                     };
                     comp.eval(val, *other_val)
                 } else {
@@ -129,6 +156,9 @@ impl Condition {
                     WorldVariable::Year => state.world.year as f32,
                     WorldVariable::Population => state.world.regions.population(),
                     WorldVariable::PopulationGrowth => state.world.population_growth_modifier,
+                    WorldVariable::DemographicTransition => {
+                        state.world.demographic_transition_modifier
+                    }
                     WorldVariable::Emissions => state.emissions.as_co2eq(),
                     WorldVariable::ExtinctionRate => state.world.extinction_rate,
                     WorldVariable::Outlook => state.outlook(),
@@ -136,6 +166,13 @@ impl Condition {
                     WorldVariable::SeaLevelRise => state.world.sea_level_rise,
                     WorldVariable::SeaLevelRiseRate => state.world.sea_level_rise_rate(),
                     WorldVariable::Precipitation => state.world.precipitation,
+                    WorldVariable::CumulativeEmissions => state.carbon_budget.co2eq,
+                    WorldVariable::OnePointFiveBudget => state.carbon_budget.remaining_1_5c(),
+                    WorldVariable::TwoDegreeBudget => state.carbon_budget.remaining_2c(),
+                    WorldVariable::NetZeroYear => state
+                        .carbon_budget
+                        .net_zero_year(state.world.year)
+                        .map_or(f32::INFINITY, |year| year as f32),
                 };
                 comp.eval(val, *other_val)
             }
@@ -222,6 +259,17 @@ impl Condition {
                     false
                 }
             }
+            Condition::RegionSeceded(seceded) => {
+                if let Some(id) = &region_id {
+                    state.world.regions[id].seceded == *seceded
+                } else {
+                    false
+                }
+            }
+            Condition::SecededRegions(comp, n) => {
+                let seceded = state.world.regions.iter().filter(|r| r.seceded).count();
+                comp.eval(seceded as f32, *n as f32)
+            }
             Condition::HasFlag(flag) => state.flags.contains(flag),
             Condition::WithoutFlag(flag) => !state.flags.contains(flag),
             Condition::HeavyProjects(comp, n) => {
@@ -235,7 +283,10 @@ impl Condition {
                     .count();
                 comp.eval(heavy_projects as f32, *n as f32)
             }
-            Condition::ProtectLand(comp, n) => comp.eval(state.protected_land, *n),
+            Condition::ProtectLand(comp, n) => comp.eval(state.land.reserved.protected, *n),
+            Condition::LandUse(land_use, comp, n) => {
+                comp.eval(state.land.allocation()[*land_use], *n)
+            }
             Condition::WaterStress(comp, n) => {
                 let water_stress =
                     state.resource_demand.of(Resource::Water) / state.resources.available.water;
@@ -1,7 +1,10 @@
-use super::{PlayerVariable, WorldVariable};
+use super::{LocalVariable, PlayerVariable, WorldVariable};
 use crate::{
     Id,
-    kinds::{Byproduct, Feedstock, Output, Resource},
+    kinds::{Byproduct, Feedstock, LandUse, Output, Resource},
+    ledger::Source,
+    migration::CLOSED_BORDERS_MULTIPLIER,
+    npcs::Reason,
     production::ProcessFeature,
     regions::{Latitude, Region},
     state::State,
@@ -11,7 +14,6 @@ use std::ops::Mul;
 use strum::{Display, EnumDiscriminants, EnumIter, EnumString, IntoStaticStr};
 
 const MIGRATION_WAVE_PERCENT_POP: f32 = 0.1;
-const CLOSED_BORDERS_MULTILPIER: f32 = 0.5;
 
 #[derive(Clone, Serialize, Deserialize, PartialEq)]
 pub enum Request {
@@ -125,14 +127,21 @@ pub enum Effect {
     ProjectRequest(Id, bool, usize),
     ProcessRequest(Id, bool, usize),
 
+    LocalVariable(LocalVariable, f32),
     Migration,
     RegionLeave,
+    RegionRejoin,
+    Reunification(f32),
     TerminationShock,
     AddRegionFlag(RegionFlag),
 
     AddFlag(Flag),
     NPCRelationship(Id, f32),
 
+    /// Swing an NPC's vote in the next election,
+    /// as a share of its current vote.
+    ElectionSwing(Id, f32),
+
     ModifyProcessByproducts(Id, Byproduct, f32),
     ModifyIndustryByproducts(Id, Byproduct, f32),
     ModifyIndustryResources(Id, Resource, f32),
@@ -145,6 +154,9 @@ pub enum Effect {
 
     ProtectLand(f32),
 
+    /// Set aside a share of land for a particular use.
+    LandUse(LandUse, f32),
+
     BailOut(usize),
     GameOver,
 }
@@ -154,8 +166,41 @@ impl AsRef<Effect> for Effect {
     }
 }
 
+/// Change a variable for the region,
+/// or for every region if none is given.
+fn change_local_variable(
+    state: &mut State,
+    region_id: Option<Id>,
+    var: LocalVariable,
+    change: f32,
+) {
+    for region in state
+        .world
+        .regions
+        .iter_mut()
+        .filter(|r| region_id.is_none_or(|id| id == r.id))
+    {
+        match var {
+            LocalVariable::Population => region.population += change,
+            LocalVariable::Outlook => region.outlook += change,
+            LocalVariable::Habitability => region.base_habitability += change,
+            LocalVariable::HeatwaveRisk => region.risk_modifiers.heatwave += change,
+            LocalVariable::DroughtRisk => region.risk_modifiers.drought += change,
+            LocalVariable::FloodRisk => region.risk_modifiers.flood += change,
+            LocalVariable::HeatResilience
+            | LocalVariable::DroughtResilience
+            | LocalVariable::FireResilience
+            | LocalVariable::FloodResilience => {
+                if let Some(hazard) = var.hazard() {
+                    *region.resilience.entry(hazard).or_default() += change;
+                }
+            }
+        }
+    }
+}
+
 fn check_game_over(state: &mut State) {
-    if !state.npcs.is_ally("The Authoritarian")
+    if !state.npcs.prevents_outlook_game_over()
         && state.outlook() < 0.
         && !state.flags.contains(&Flag::LifeGoesOn)
     {
@@ -204,12 +249,16 @@ impl Effect {
             EffectKind::UnlocksNPC => Effect::UnlocksNPC(default_npc),
             EffectKind::ProjectRequest => Effect::ProjectRequest(default_project, true, 20),
             EffectKind::ProcessRequest => Effect::ProcessRequest(default_process, true, 20),
+            EffectKind::LocalVariable => Effect::LocalVariable(LocalVariable::Outlook, 0.),
             EffectKind::Migration => Effect::Migration,
             EffectKind::RegionLeave => Effect::RegionLeave,
+            EffectKind::RegionRejoin => Effect::RegionRejoin,
+            EffectKind::Reunification => Effect::Reunification(0.25),
             EffectKind::TerminationShock => Effect::TerminationShock,
             EffectKind::AddRegionFlag => Effect::AddRegionFlag(RegionFlag::Protests),
             EffectKind::AddFlag => Effect::AddFlag(Flag::Vegan),
             EffectKind::NPCRelationship => Effect::NPCRelationship(default_npc, 0.),
+            EffectKind::ElectionSwing => Effect::ElectionSwing(default_npc, 0.),
             EffectKind::ModifyProcessByproducts => {
                 Effect::ModifyProcessByproducts(default_process, Byproduct::Co2, 0.)
             }
@@ -228,11 +277,26 @@ impl Effect {
             EffectKind::IncomeOutlookChange => Effect::IncomeOutlookChange(0.),
             EffectKind::ProjectCostModifier => Effect::ProjectCostModifier(default_project, 0.),
             EffectKind::ProtectLand => Effect::ProtectLand(0.1),
+            EffectKind::LandUse => Effect::LandUse(LandUse::Protected, 0.1),
             EffectKind::BailOut => Effect::BailOut(20),
             EffectKind::GameOver => Effect::GameOver,
         }
     }
 
+    /// If this effect applies to a particular region,
+    /// e.g. an event's region or a project's targeted regions.
+    pub fn is_regional(&self) -> bool {
+        matches!(
+            self,
+            Effect::LocalVariable(..)
+                | Effect::Migration
+                | Effect::RegionLeave
+                | Effect::RegionRejoin
+                | Effect::Reunification(..)
+                | Effect::AddRegionFlag(..)
+        )
+    }
+
     pub fn process_id(&self) -> Option<Id> {
         match self {
             Effect::OutputForProcess(id, _)
@@ -272,6 +336,15 @@ impl Effect {
             _ => None,
         }
     }
+
+    pub fn npc_id(&self) -> Option<Id> {
+        match self {
+            Effect::UnlocksNPC(id)
+            | Effect::NPCRelationship(id, _)
+            | Effect::ElectionSwing(id, _) => Some(*id),
+            _ => None,
+        }
+    }
 }
 
 impl Effect {
@@ -310,10 +383,9 @@ impl Effect {
                 }
             }
             Effect::BailOut(amount) => {
-                if state.political_capital < 0 {
-                    state.political_capital = 0;
-                }
-                state.political_capital += *amount as isize;
+                // Clear any debt before adding the bail out.
+                let amount = *amount as isize - state.political_capital.min(0);
+                state.change_political_capital(amount, Source::Event);
             }
             Effect::WorldVariable(var, change) => {
                 match var {
@@ -326,6 +398,9 @@ impl Effect {
                     WorldVariable::PopulationGrowth => {
                         state.world.population_growth_modifier += *change
                     }
+                    WorldVariable::DemographicTransition => {
+                        state.world.demographic_transition_modifier += *change
+                    }
                     WorldVariable::Emissions => {
                         state.byproducts.modifier.co2 += *change * 1e15; // effect in Gt
                         state.emissions.co2 += *change * 1e15; // Apply immediately
@@ -343,11 +418,23 @@ impl Effect {
                         state.world.sea_level_rise_modifier += *change
                     }
                     WorldVariable::Precipitation => state.world.precipitation += *change,
+                    WorldVariable::CumulativeEmissions => state.carbon_budget.add(*change),
+                    // Both budgets are what's left after cumulative
+                    // emissions, so they change together.
+                    WorldVariable::OnePointFiveBudget | WorldVariable::TwoDegreeBudget => {
+                        state.carbon_budget.add(-*change)
+                    }
+                    // Projected from the emissions trend.
+                    WorldVariable::NetZeroYear => (),
                 }
             }
             Effect::PlayerVariable(var, change) => match var {
-                PlayerVariable::PoliticalCapital => state.political_capital += *change as isize,
-                PlayerVariable::ResearchPoints => state.research_points += *change as isize,
+                PlayerVariable::PoliticalCapital => {
+                    state.change_political_capital(*change as isize, Source::Event)
+                }
+                PlayerVariable::ResearchPoints => {
+                    state.change_research_points(*change as isize, Source::Event)
+                }
                 _ => (),
             },
             Effect::RegionHabitability(latitude, change) => {
@@ -452,10 +539,13 @@ impl Effect {
                     .requests
                     .push((Request::Process, *id, *active, *bounty));
             }
+            Effect::LocalVariable(var, change) => {
+                change_local_variable(state, region_id, *var, *change);
+            }
             Effect::Migration => {
                 if let Some(id) = &region_id {
                     let modifier = if state.flags.contains(&Flag::ClosedBorders) {
-                        CLOSED_BORDERS_MULTILPIER
+                        CLOSED_BORDERS_MULTIPLIER
                     } else {
                         1.
                     };
@@ -464,12 +554,15 @@ impl Effect {
                     state.world.regions[id].population -= leave_pop;
 
                     // Find the most habitable regions
-                    let mean_habitability: f32 = state.world.regions.habitability();
+                    let sea_level_rise = state.world.sea_level_rise;
+                    let mean_habitability: f32 = state.world.regions.habitability(sea_level_rise);
                     let target_regions: Vec<&mut Region> = state
                         .world
                         .regions
                         .iter_mut()
-                        .filter(|r| &r.id != id && r.habitability() > mean_habitability)
+                        .filter(|r| {
+                            &r.id != id && r.habitability(sea_level_rise) > mean_habitability
+                        })
                         .collect();
                     let per_region = leave_pop / target_regions.len() as f32;
                     for region in target_regions {
@@ -479,7 +572,21 @@ impl Effect {
             }
             Effect::RegionLeave => {
                 if let Some(id) = &region_id {
-                    state.world.regions[id].seceded = true;
+                    state.world.regions[id].secede(&state.world.processes);
+                }
+            }
+            Effect::RegionRejoin => {
+                if let Some(id) = &region_id {
+                    state.world.regions[id].rejoin();
+                }
+            }
+            Effect::Reunification(progress) => {
+                // Negotiate with the event's region,
+                // or with every seceded region if there isn't one.
+                for region in state.world.regions.iter_mut() {
+                    if region_id.is_none_or(|id| id == region.id) {
+                        region.negotiate_reunification(*progress);
+                    }
                 }
             }
             Effect::AddRegionFlag(flag) => {
@@ -491,7 +598,11 @@ impl Effect {
                 state.flags.push(*flag);
             }
             Effect::NPCRelationship(id, change) => {
-                state.npcs[id].relationship += change;
+                let year = state.world.year;
+                state.npcs[id].change_relationship(*change, Reason::Event, year);
+            }
+            Effect::ElectionSwing(id, change) => {
+                *state.elections.swings.entry(*id).or_default() += change;
             }
 
             Effect::ModifyProcessByproducts(id, byproduct, change) => {
@@ -553,13 +664,19 @@ impl Effect {
                 state.world.temperature_modifier -= temp;
             }
             Effect::ProtectLand(percent) => {
-                state.protected_land += percent;
+                state.land.reserved.protected += percent;
+            }
+            Effect::LandUse(land_use, percent) => {
+                state.land.reserved[*land_use] += percent;
             }
         }
     }
 
-    pub fn unapply(&self, state: &mut State, _region_id: Option<Id>) {
+    pub fn unapply(&self, state: &mut State, region_id: Option<Id>) {
         match self {
+            Effect::LocalVariable(var, change) => {
+                change_local_variable(state, region_id, *var, -*change);
+            }
             Effect::WorldVariable(var, change) => {
                 match var {
                     WorldVariable::Year => {
@@ -571,6 +688,9 @@ impl Effect {
                     WorldVariable::PopulationGrowth => {
                         state.world.population_growth_modifier -= *change
                     }
+                    WorldVariable::DemographicTransition => {
+                        state.world.demographic_transition_modifier -= *change
+                    }
                     WorldVariable::Emissions => {
                         state.byproducts.modifier.co2 -= *change * 1e15;
                         state.emissions.co2 -= *change * 1e15; // Apply immediately
@@ -585,11 +705,20 @@ impl Effect {
                         state.world.sea_level_rise_modifier -= *change
                     }
                     WorldVariable::Precipitation => state.world.precipitation -= *change,
+                    WorldVariable::CumulativeEmissions => state.carbon_budget.add(-*change),
+                    WorldVariable::OnePointFiveBudget | WorldVariable::TwoDegreeBudget => {
+                        state.carbon_budget.add(*change)
+                    }
+                    WorldVariable::NetZeroYear => (),
                 }
             }
             Effect::PlayerVariable(var, change) => match var {
-                PlayerVariable::PoliticalCapital => state.political_capital -= *change as isize,
-                PlayerVariable::ResearchPoints => state.research_points -= *change as isize,
+                PlayerVariable::PoliticalCapital => {
+                    state.change_political_capital(-(*change as isize), Source::Event)
+                }
+                PlayerVariable::ResearchPoints => {
+                    state.change_research_points(-(*change as isize), Source::Event)
+                }
                 _ => (),
             },
             Effect::RegionHabitability(latitude, change) => {
@@ -667,7 +796,11 @@ impl Effect {
                 state.feedstocks.available[*feedstock] /= 1. + pct_change;
             }
             Effect::NPCRelationship(id, change) => {
-                state.npcs[id].relationship -= change;
+                let year = state.world.year;
+                state.npcs[id].change_relationship(-change, Reason::Event, year);
+            }
+            Effect::ElectionSwing(id, change) => {
+                *state.elections.swings.entry(*id).or_default() -= change;
             }
             Effect::ModifyProcessByproducts(id, byproduct, change) => {
                 state.world.processes[id].byproduct_modifiers[*byproduct] -= change;
@@ -726,7 +859,10 @@ impl Effect {
                 state.world.temperature_modifier += temp;
             }
             Effect::ProtectLand(percent) => {
-                state.protected_land -= percent;
+                state.land.reserved.protected -= percent;
+            }
+            Effect::LandUse(land_use, percent) => {
+                state.land.reserved[*land_use] -= percent;
             }
             Effect::AddFlag(flag) => {
                 if let Some(idx) = state.flags.iter().position(|x| x == flag) {
@@ -752,6 +888,35 @@ impl Effect {
     }
 }
 
+impl Effect {
+    /// If this effect has an amount that can be scaled.
+    pub fn is_scalable(&self) -> bool {
+        matches!(
+            self,
+            Effect::WorldVariable(..)
+                | Effect::PlayerVariable(..)
+                | Effect::Resource(..)
+                | Effect::Demand(..)
+                | Effect::Output(..)
+                | Effect::DemandAmount(..)
+                | Effect::OutputForFeature(..)
+                | Effect::OutputForProcess(..)
+                | Effect::Feedstock(..)
+                | Effect::ModifyIndustryByproducts(..)
+                | Effect::ModifyIndustryResources(..)
+                | Effect::ModifyIndustryResourcesAmount(..)
+                | Effect::ModifyIndustryDemand(..)
+                | Effect::ModifyEventProbability(..)
+                | Effect::DemandOutlookChange(..)
+                | Effect::IncomeOutlookChange(..)
+                | Effect::LocalVariable(..)
+                | Effect::ProjectCostModifier(..)
+                | Effect::ProtectLand(..)
+                | Effect::LandUse(..)
+        )
+    }
+}
+
 // For scaling effects by float
 impl Mul<f32> for Effect {
     type Output = Self;
@@ -784,8 +949,10 @@ impl Mul<f32> for Effect {
                 Effect::DemandOutlookChange(output, val * rhs)
             }
             Effect::IncomeOutlookChange(val) => Effect::IncomeOutlookChange(val * rhs),
+            Effect::LocalVariable(var, val) => Effect::LocalVariable(var, val * rhs),
             Effect::ProjectCostModifier(id, val) => Effect::ProjectCostModifier(id, val * rhs),
             Effect::ProtectLand(val) => Effect::ProtectLand(val * rhs),
+            Effect::LandUse(land_use, val) => Effect::LandUse(land_use, val * rhs),
             _ => self,
         }
     }
@@ -834,7 +1001,7 @@ mod tests {
             project.points = 100;
             project.status = Status::Building;
             for i in 0..40 {
-                project.advance(state.world.year + i);
+                project.advance(state.world.year + i, None);
             }
             assert_eq!(project.status, Status::Active);
 
@@ -11,6 +11,11 @@ pub struct EventPool {
     // (phase, event id, region id, countdown)
     pub queue: Vec<(Phase, Id, Option<Id>, usize)>,
     pub triggered: Vec<(Phase, Id, Option<Id>)>,
+
+    /// Queued events that stay queued, retrying each roll
+    /// of their phase, until they trigger.
+    #[serde(default)]
+    pub persistent: HashSet<Id>,
 }
 
 impl EventPool {
@@ -19,6 +24,7 @@ impl EventPool {
             events,
             queue: Vec::new(),
             triggered: Vec::new(),
+            persistent: HashSet::new(),
         }
     }
 
@@ -27,6 +33,12 @@ impl EventPool {
         self.queue.push((phase, id, region_id, years));
     }
 
+    /// Queue an event that keeps retrying if it fails its roll.
+    pub fn queue_persistent(&mut self, id: Id, region_id: Option<Id>, years: usize) {
+        self.persistent.insert(id);
+        self.queue_event(id, region_id, years);
+    }
+
     pub fn roll_for_phase(&mut self, phase: Phase, state: &State) -> Vec<(Event, Option<Id>)> {
         // Prevent duplicate events
         let mut existing: HashSet<&Id> = HashSet::new();
@@ -65,6 +77,11 @@ impl EventPool {
                 let ev = &mut self.events[&ev_id];
                 if ev.roll(state, region_id) {
                     self.triggered.push((ev.phase, ev_id, region_id));
+                    self.persistent.remove(&ev_id);
+                } else if self.persistent.contains(&ev_id) {
+                    self.queue[i].3 = 1;
+                    i += 1;
+                    continue;
                 }
                 self.queue.remove(i);
             } else {
@@ -309,6 +326,7 @@ mod test {
             events,
             queue: vec![],
             triggered: vec![],
+            persistent: HashSet::new(),
         };
 
         let mut state = State::default();
@@ -354,6 +372,7 @@ mod test {
             events,
             queue: vec![],
             triggered: vec![],
+            persistent: HashSet::new(),
         };
 
         let mut state = State::default();
@@ -402,6 +421,7 @@ mod test {
             events,
             queue: vec![(Phase::WorldMain, id, None, 2)],
             triggered: vec![],
+            persistent: HashSet::new(),
         };
 
         let state = State::default();
@@ -435,6 +455,7 @@ mod test {
                 (Phase::WorldMain, id, None),
                 (Phase::WorldMain, id, None),
             ],
+            persistent: HashSet::new(),
         };
 
         let state = State::default();
@@ -3,6 +3,7 @@ mod effects;
 mod events;
 mod icons;
 mod probability;
+mod storylines;
 mod vars;
 
 pub use self::{
@@ -19,5 +20,6 @@ pub use self::{
     events::{Event, EventPool, Phase},
     icons::{ICON_EVENTS, IconEvent},
     probability::{Likelihood, Probability},
+    storylines::{Branch, Stage, Storyline, StorylineProgress},
     vars::{LocalVariable, PlayerVariable, WorldVariable},
 };
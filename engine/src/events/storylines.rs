@@ -0,0 +1,316 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::Condition;
+use crate::{HasId, Id, state::State};
+
+/// A storyline groups events into ordered stages.
+/// When a stage's event occurs the storyline branches
+/// to its next stage, depending on what dialogue response
+/// was selected and what conditions are satisfied.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Storyline {
+    pub id: Id,
+    pub name: String,
+
+    /// The first stage is the entry point; its event
+    /// triggers like any other event. Events for later stages
+    /// should usually be locked so they only occur as
+    /// part of this storyline.
+    pub stages: Vec<Stage>,
+    pub notes: String,
+}
+impl Default for Storyline {
+    fn default() -> Self {
+        Self {
+            id: Id::new_v4(),
+            name: "New Storyline".into(),
+            stages: vec![],
+            notes: "".into(),
+        }
+    }
+}
+
+impl Display for Storyline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl HasId for Storyline {
+    fn id(&self) -> &Id {
+        &self.id
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Stage {
+    /// The event for this stage.
+    pub event: Id,
+
+    /// Branches are checked in their defined order
+    /// and the first matching branch is taken.
+    /// If no branch matches the storyline ends.
+    pub branches: Vec<Branch>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Branch {
+    /// If set, the id of the dialogue response that
+    /// must have been selected during this stage's event.
+    pub response: Option<usize>,
+    pub conditions: Vec<Condition>,
+
+    /// The index of the next stage,
+    /// or `None` to end the storyline.
+    pub next: Option<usize>,
+
+    /// How many rolls (of the next event's phase)
+    /// to wait before triggering the next stage's event.
+    pub delay: usize,
+}
+impl Branch {
+    fn matches(&self, state: &State, response: Option<usize>, region_id: Option<Id>) -> bool {
+        let response_matches = match self.response {
+            Some(id) => response == Some(id),
+            None => true,
+        };
+        response_matches && state.eval_conditions(&self.conditions, region_id)
+    }
+}
+
+/// Tracks how far along a storyline is.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct StorylineProgress {
+    /// The current stage, if the storyline has started.
+    pub stage: Option<usize>,
+
+    /// If the current stage's event has occurred
+    /// and the storyline is waiting to branch.
+    pub awaiting: bool,
+
+    /// If the current stage's event has been shown to the player.
+    /// Storylines only branch once it has, so that
+    /// the response to it is known.
+    pub shown: bool,
+
+    pub finished: bool,
+
+    /// The dialogue response selected
+    /// during the current stage's event.
+    pub response: Option<usize>,
+
+    /// The region the current stage's event occurred in, if any.
+    pub region: Option<Id>,
+
+    /// Stages reached so far, in order.
+    pub history: Vec<usize>,
+}
+
+impl Storyline {
+    /// Returns the index of the next stage to move to
+    /// and the delay before its event triggers,
+    /// or `None` if the storyline should end.
+    pub fn next_stage(
+        &self,
+        state: &State,
+        progress: &StorylineProgress,
+    ) -> Option<(usize, usize)> {
+        let stage = self.stages.get(progress.stage?)?;
+        stage
+            .branches
+            .iter()
+            .find(|branch| branch.matches(state, progress.response, progress.region))
+            .and_then(|branch| branch.next.map(|next| (next, branch.delay)))
+            .filter(|(next, _)| *next < self.stages.len())
+    }
+
+    /// Indices of stages that can't be reached from the first stage.
+    pub fn unreachable_stages(&self) -> Vec<usize> {
+        let mut reached = vec![false; self.stages.len()];
+        let mut frontier = vec![0];
+        while let Some(idx) = frontier.pop() {
+            if idx >= self.stages.len() || reached[idx] {
+                continue;
+            }
+            reached[idx] = true;
+            frontier.extend(self.stages[idx].branches.iter().filter_map(|b| b.next));
+        }
+        reached
+            .into_iter()
+            .enumerate()
+            .filter(|(_, reached)| !reached)
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::events::{Comparator, Event, Phase, WorldVariable};
+
+    fn gen_storyline(state: &mut State) -> Storyline {
+        let events: Vec<Event> = (0..3)
+            .map(|i| Event {
+                name: format!("Stage {i}"),
+                locked: i > 0,
+                ..Default::default()
+            })
+            .collect();
+        let stages = vec![
+            Stage {
+                event: events[0].id,
+                branches: vec![
+                    Branch {
+                        response: Some(1),
+                        next: Some(2),
+                        delay: 1,
+                        ..Default::default()
+                    },
+                    Branch {
+                        next: Some(1),
+                        delay: 1,
+                        ..Default::default()
+                    },
+                ],
+            },
+            Stage {
+                event: events[1].id,
+                branches: vec![Branch {
+                    conditions: vec![Condition::WorldVariable(
+                        WorldVariable::Year,
+                        Comparator::Greater,
+                        3000.,
+                    )],
+                    next: Some(2),
+                    delay: 1,
+                    ..Default::default()
+                }],
+            },
+            Stage {
+                event: events[2].id,
+                branches: vec![],
+            },
+        ];
+        for event in events {
+            state.event_pool.events.push(event);
+        }
+        Storyline {
+            name: "Test Storyline".into(),
+            stages,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_storyline_branching() {
+        let mut state = State::default();
+        let storyline = gen_storyline(&mut state);
+        let id = storyline.id;
+        let events: Vec<Id> = storyline.stages.iter().map(|s| s.event).collect();
+        state.world.storylines.push(storyline);
+
+        // Starting the storyline
+        state.apply_event(events[0], None);
+        assert_eq!(state.storylines[&id].stage, Some(0));
+        assert!(state.storylines[&id].awaiting);
+
+        // Selecting the response should take the first branch
+        state.record_response(events[0], 1);
+        state.finish_event(events[0]);
+        state.advance_storylines();
+        let progress = &state.storylines[&id];
+        assert_eq!(progress.stage, Some(2));
+        assert!(!progress.awaiting);
+        assert_eq!(progress.history, vec![0, 2]);
+        assert!(
+            state
+                .event_pool
+                .queue
+                .iter()
+                .any(|(_, ev_id, _, _)| *ev_id == events[2])
+        );
+
+        // The last stage has no branches so the storyline ends
+        state.apply_event(events[2], None);
+        state.finish_event(events[2]);
+        state.advance_storylines();
+        assert!(state.storylines[&id].finished);
+    }
+
+    #[test]
+    fn test_storyline_ends_without_matching_branch() {
+        let mut state = State::default();
+        let storyline = gen_storyline(&mut state);
+        let id = storyline.id;
+        let events: Vec<Id> = storyline.stages.iter().map(|s| s.event).collect();
+        state.world.storylines.push(storyline);
+
+        // No response, so the second branch is taken
+        state.apply_event(events[0], None);
+        state.finish_event(events[0]);
+        state.advance_storylines();
+        assert_eq!(state.storylines[&id].stage, Some(1));
+
+        // The year condition isn't met so the storyline ends
+        state.apply_event(events[1], None);
+        state.finish_event(events[1]);
+        state.advance_storylines();
+        assert!(state.storylines[&id].finished);
+        assert_eq!(state.storylines[&id].history, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_storyline_waits_for_stage_event() {
+        fastrand::seed(0);
+        let mut state = State::default();
+        let storyline = gen_storyline(&mut state);
+        let id = storyline.id;
+        let events: Vec<Id> = storyline.stages.iter().map(|s| s.event).collect();
+        state.world.storylines.push(storyline);
+        state.event_pool.events[&events[0]].occurred = true;
+
+        // Rolling again before the stage event has been
+        // shown shouldn't branch the storyline
+        state.apply_event(events[0], None);
+        state.roll_events(Phase::PlanningPlan);
+        assert_eq!(state.storylines[&id].stage, Some(0));
+        assert!(state.storylines[&id].awaiting);
+
+        state.record_response(events[0], 1);
+        state.finish_event(events[0]);
+        state.advance_storylines();
+        assert_eq!(state.storylines[&id].stage, Some(2));
+
+        // A queued stage event that fails its roll stays queued
+        let queued = |state: &State| {
+            state
+                .event_pool
+                .queue
+                .iter()
+                .any(|(_, ev_id, _, _)| *ev_id == events[2])
+        };
+        state.event_pool.events[&events[2]].prob_modifier = 0.;
+        state.roll_events(Phase::WorldMain);
+        state.roll_events(Phase::WorldMain);
+        assert!(queued(&state));
+
+        state.event_pool.events[&events[2]].prob_modifier = 1.;
+        let rolled = state.roll_events(Phase::WorldMain);
+        assert!(rolled.iter().any(|ev| ev.id == events[2]));
+        assert!(!queued(&state));
+        assert!(state.storylines[&id].awaiting);
+    }
+
+    #[test]
+    fn test_unreachable_stages() {
+        let mut state = State::default();
+        let mut storyline = gen_storyline(&mut state);
+        assert!(storyline.unreachable_stages().is_empty());
+
+        storyline.stages[0].branches.clear();
+        assert_eq!(storyline.unreachable_stages(), vec![1, 2]);
+    }
+}
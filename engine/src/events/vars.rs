@@ -1,3 +1,4 @@
+use crate::disasters::Hazard;
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter, EnumString, IntoStaticStr};
 
@@ -24,6 +25,11 @@ pub enum WorldVariable {
     SeaLevelRiseRate,
     Precipitation,
     PopulationGrowth,
+    DemographicTransition,
+    CumulativeEmissions,
+    OnePointFiveBudget,
+    TwoDegreeBudget,
+    NetZeroYear,
 }
 
 #[derive(
@@ -42,6 +48,26 @@ pub enum LocalVariable {
     Population,
     Outlook,
     Habitability,
+    HeatwaveRisk,
+    DroughtRisk,
+    FloodRisk,
+    HeatResilience,
+    DroughtResilience,
+    FireResilience,
+    FloodResilience,
+}
+
+impl LocalVariable {
+    /// The hazard a resilience variable protects against.
+    pub fn hazard(&self) -> Option<Hazard> {
+        match self {
+            Self::HeatResilience => Some(Hazard::Heat),
+            Self::DroughtResilience => Some(Hazard::Drought),
+            Self::FireResilience => Some(Hazard::Fire),
+            Self::FloodResilience => Some(Hazard::Flood),
+            _ => None,
+        }
+    }
 }
 
 #[derive(
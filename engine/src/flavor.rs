@@ -3,7 +3,11 @@ use serde_bytes;
 use std::collections::BTreeMap;
 use strum::{EnumIter, EnumString, IntoStaticStr};
 
-use crate::events::{Condition, Effect};
+use crate::{
+    Id,
+    events::{Condition, Effect},
+    state::State,
+};
 
 pub type ProjectLockers = BTreeMap<usize, usize>;
 
@@ -71,7 +75,9 @@ pub struct ProcessFlavor {
     pub description: String,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, PartialEq, Serialize, Deserialize, Default,
+)]
 pub struct NPCFlavor {
     pub description: String,
     pub effects: String,
@@ -94,6 +100,11 @@ pub struct DialogueLine {
     pub next: Option<DialogueNext>,
     pub speaker: Speaker,
     pub text: String,
+
+    /// Alternate texts for this line. The first variant
+    /// with all its conditions satisfied replaces `text`.
+    #[serde(default)]
+    pub variants: Vec<LineVariant>,
 }
 impl DialogueLine {
     pub fn has_decision(&self) -> bool {
@@ -101,6 +112,20 @@ impl DialogueLine {
             matches!(next, DialogueNext::Responses(..))
         })
     }
+
+    /// The text to show for this line given the current state.
+    pub fn text_for(
+        &self,
+        state: &State,
+        region_id: Option<Id>,
+    ) -> &str {
+        self.variants
+            .iter()
+            .find(|variant| {
+                state.eval_conditions(&variant.conditions, region_id)
+            })
+            .map_or(&self.text, |variant| &variant.text)
+    }
 }
 impl Default for DialogueLine {
     fn default() -> Self {
@@ -109,8 +134,43 @@ impl Default for DialogueLine {
             next: None,
             speaker: Speaker::Game,
             text: "".into(),
+            variants: vec![],
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Serialize, Deserialize, Default,
+)]
+pub struct LineVariant {
+    pub conditions: Vec<Condition>,
+    pub text: String,
+}
+
+/// Replace `{key}` placeholders in the text
+/// with the values returned by `lookup`.
+/// Placeholders without a value are left as-is.
+pub fn interpolate(
+    text: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        result.push_str(&rest[..start]);
+        let placeholder = &rest[start..start + len + 1];
+        let key = &placeholder[1..placeholder.len() - 1];
+        match lookup(key) {
+            Some(value) => result.push_str(&value),
+            None => result.push_str(placeholder),
         }
+        rest = &rest[start + len + 1..];
     }
+    result.push_str(rest);
+    result
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -284,3 +344,25 @@ impl<'de> Deserialize<'de> for Speaker {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_interpolate() {
+        let lookup = |key: &str| match key {
+            "region" => Some("Antarctica".to_string()),
+            "temperature" => Some("+1.5C".to_string()),
+            _ => None,
+        };
+        assert_eq!(
+            interpolate("The heat in {region} is {temperature}.", lookup),
+            "The heat in Antarctica is +1.5C."
+        );
+        assert_eq!(
+            interpolate("{unknown} stays, {region", lookup),
+            "{unknown} stays, {region"
+        );
+    }
+}
@@ -96,10 +96,15 @@ impl Industry {
         (self.byproducts * (self.byproduct_modifiers + 1.).max(0.)).or_negative(self.byproducts)
     }
 
+    /// Extinction rate contribution from
+    /// everything but land use.
+    pub fn biodiversity_pressure(&self) -> f32 {
+        self.adj_byproducts().biodiversity / 1e4 * 100.
+    }
+
     pub fn extinction_rate(&self, starting_land: f32) -> f32 {
-        let pressure = self.adj_byproducts().biodiversity;
         let land = self.adj_resources().land;
-        (pressure / 1e4 + land / starting_land) * 100.
+        self.biodiversity_pressure() + land / starting_land * 100.
     }
 }
 
@@ -146,6 +146,8 @@ macro_rules! define_enum_map {
             }
 
             impl [<$name Map>] {
+                // Not every map is used in a `Reserve` or `Modifiable`.
+                #[allow(dead_code)]
                 const N: usize = count!($($field)*);
 
                 pub fn values(&self) -> [f32; count!($($field)*)] {
@@ -424,6 +426,15 @@ define_enum_map!(Output {
     AnimalCalories
 });
 
+define_enum_map!(LandUse {
+    Cropland,
+    Pasture,
+    Energy,
+    Urban,
+    Protected,
+    Wild
+});
+
 define_enum_map!(Feedstock {
     Soil,
     Oil,
@@ -640,6 +651,23 @@ impl Display for Resource {
     }
 }
 
+impl Display for LandUse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                LandUse::Cropland => "Cropland",
+                LandUse::Pasture => "Pasture",
+                LandUse::Energy => "Energy",
+                LandUse::Urban => "Urban",
+                LandUse::Protected => "Protected",
+                LandUse::Wild => "Wild",
+            }
+        )
+    }
+}
+
 impl Display for Byproduct {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -0,0 +1,222 @@
+use serde::{Deserialize, Serialize};
+
+use crate::kinds::{KindMap, LandUse, LandUseMap, Output, OutputMap};
+
+/// Share of idle land that returns to the wild every year.
+const REGROWTH_RATE: f32 = 0.1;
+
+/// Biodiversity pressure per share of land in each use.
+/// Land in use matches the pressure processes and
+/// industries used to contribute directly;
+/// protected land helps ecosystems recover.
+const EXTINCTION_WEIGHTS: LandUseMap = LandUseMap {
+    cropland: 100.,
+    pasture: 100.,
+    energy: 100.,
+    urban: 100.,
+    protected: -25.,
+    wild: 0.,
+};
+
+/// How the world's land is allocated between uses,
+/// with each value a share of the total land.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Land {
+    /// Land set aside for each use by policies and events,
+    /// which processes can't use.
+    pub reserved: LandUseMap,
+
+    /// Land actively used by processes and industries.
+    pub used: LandUseMap,
+
+    /// Land that is no longer used
+    /// but hasn't yet returned to the wild.
+    pub idle: LandUseMap,
+
+    /// Land used at the end of the previous year.
+    pub last_used: LandUseMap,
+}
+
+impl Default for Land {
+    fn default() -> Self {
+        Self {
+            reserved: LandUseMap {
+                protected: 0.1, // Starts at 10%
+                ..Default::default()
+            },
+            used: LandUseMap::default(),
+            idle: LandUseMap::default(),
+            last_used: LandUseMap::default(),
+        }
+    }
+}
+
+impl Land {
+    /// The category that land used
+    /// to produce an output falls under.
+    pub fn use_for_output(output: Output) -> LandUse {
+        match output {
+            Output::PlantCalories => LandUse::Cropland,
+            Output::AnimalCalories => LandUse::Pasture,
+            Output::Fuel | Output::Electricity => LandUse::Energy,
+        }
+    }
+
+    /// The current share of land in each use.
+    /// Whatever isn't otherwise allocated is wild.
+    pub fn allocation(&self) -> LandUseMap {
+        let mut allocation = self.used + self.reserved + self.idle;
+        allocation.wild = 0.;
+        allocation.wild = (1. - allocation.sum()).max(0.);
+        allocation
+    }
+
+    /// The share of land that processes can use,
+    /// i.e. whatever isn't reserved or built up by industries.
+    pub fn available(&self) -> f32 {
+        (1. - self.reserved.sum() - self.used.urban).max(0.)
+    }
+
+    /// How much of the available land (out of `total`) each output
+    /// can use, given the land each needs. If there isn't enough
+    /// to go around it's split in proportion to need, so that
+    /// one output can't take all of it, e.g. farms taking the land
+    /// energy needs and then lacking the energy to farm next year.
+    pub fn budgets(&self, total: f32, required: &OutputMap) -> OutputMap {
+        let available = total * self.available();
+        let needed = required.sum();
+        let mut budgets = *required;
+        for budget in budgets.values_mut() {
+            *budget = if needed > available {
+                *budget * available / needed
+            } else {
+                available
+            };
+        }
+        budgets
+    }
+
+    /// Land that's no longer used lies idle and gradually
+    /// returns to the wild; idle land is put back
+    /// to use first when demand rises again.
+    pub fn transition(&mut self) {
+        for (k, idle) in self.idle.items_mut() {
+            let released = (self.last_used[k] - self.used[k]).max(0.);
+            let reclaimed = (self.used[k] - self.last_used[k]).max(0.);
+            *idle = (*idle * (1. - REGROWTH_RATE) - reclaimed).max(0.) + released;
+            if *idle < 1e-4 {
+                *idle = 0.;
+            }
+        }
+
+        // Idle land can't take up more than
+        // what's left after used and reserved land.
+        let room = (1. - self.used.sum() - self.reserved.sum()).max(0.);
+        let idle = self.idle.sum();
+        if idle > room {
+            self.idle = self.idle * (room / idle);
+        }
+        self.last_used = self.used;
+    }
+
+    pub fn extinction_rate(&self) -> f32 {
+        (self.allocation() * EXTINCTION_WEIGHTS).sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_land_transitions() {
+        let mut land = Land::default();
+        land.used.cropland = 0.3;
+        land.transition();
+
+        let allocation = land.allocation();
+        assert_eq!(allocation.cropland, 0.3);
+        assert_eq!(allocation.protected, 0.1);
+        assert!((allocation.wild - 0.6).abs() < 1e-6);
+        assert!((allocation.sum() - 1.).abs() < 1e-6);
+
+        // Abandoned cropland doesn't immediately become wild.
+        land.used.cropland = 0.1;
+        land.transition();
+        assert!((land.allocation().cropland - 0.3).abs() < 1e-6);
+
+        // But it regrows over time.
+        land.transition();
+        let cropland = land.allocation().cropland;
+        assert!(cropland < 0.3 && cropland > 0.1);
+
+        // Idle land is reused first.
+        land.used.cropland = 0.2;
+        land.transition();
+        assert!(land.allocation().cropland < cropland);
+
+        // More protection means less land
+        // available and less biodiversity pressure.
+        let pressure = land.extinction_rate();
+        land.reserved.protected = 0.5;
+        assert_eq!(land.available(), 0.5);
+        assert!(land.extinction_rate() < pressure);
+
+        // Idle land never pushes the allocation past the total.
+        land.used.pasture = 0.5;
+        land.transition();
+        land.used.pasture = 0.;
+        land.used.cropland = 0.45;
+        land.transition();
+        assert!((land.allocation().sum() - 1.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_land_budgets() {
+        let land = Land::default();
+        let required = OutputMap {
+            plant_calories: 60.,
+            animal_calories: 30.,
+            electricity: 10.,
+            fuel: 0.,
+        };
+
+        // Plenty of land for everything.
+        let budgets = land.budgets(200., &required);
+        assert_eq!(budgets.electricity, 180.);
+
+        // Not enough, so it's split by need.
+        let budgets = land.budgets(50., &required);
+        assert!((budgets.sum() - 45.).abs() < 1e-4);
+        assert!((budgets.plant_calories - 27.).abs() < 1e-4);
+        assert!((budgets.electricity - 4.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_land_shortage_is_stable() {
+        use crate::state::State;
+
+        // Land runs short around the 2060s, after which
+        // production and extinction shouldn't oscillate.
+        let mut state = State::default();
+        let mut tgav = 1.2;
+        let mut last: Option<(f32, f32, f32)> = None;
+        for _ in 0..80 {
+            state.step_year(tgav);
+            tgav += 0.025;
+
+            let plant = state.produced.of(Output::PlantCalories);
+            let animal = state.produced.of(Output::AnimalCalories);
+            let extinction = state.world.extinction_rate;
+            assert!(plant > 0. && animal > 0.);
+            assert!(state.land.allocation().sum() <= 1. + 1e-4);
+            if let Some((last_plant, last_animal, last_extinction)) = last {
+                assert!((plant - last_plant).abs() / last_plant < 0.1);
+                assert!((animal - last_animal).abs() / last_animal < 0.1);
+                assert!((extinction - last_extinction).abs() / last_extinction < 0.1);
+            }
+            last = Some((plant, animal, extinction));
+        }
+        assert_eq!(state.land.allocation().wild, 0.);
+    }
+}
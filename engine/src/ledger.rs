@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoStaticStr};
+
+/// What's being spent or earned.
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, EnumIter, Display,
+)]
+pub enum Currency {
+    PoliticalCapital,
+    Research,
+}
+
+/// Where a change in political capital
+/// or research points came from.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    EnumIter,
+    IntoStaticStr,
+    Display,
+)]
+pub enum Source {
+    /// Event and project effects.
+    Event,
+
+    /// Bounties for fulfilling NPC requests.
+    Request,
+
+    /// How the world has changed over the cycle.
+    Outlook,
+
+    /// Bonuses for completing projects.
+    Completion,
+
+    /// The goodwill of the early years.
+    Honeymoon,
+
+    /// Implementing projects and upgrades
+    /// and buying research or infrastructure points.
+    ProjectCost,
+
+    /// Costs paid back when something is stopped or undone.
+    Refund,
+
+    /// The upkeep of online projects.
+    Upkeep,
+
+    /// Winning factions over for a vote.
+    Bargain,
+
+    /// Repealing policies.
+    Repeal,
+
+    /// Campaigning in elections.
+    Campaign,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Entry {
+    pub currency: Currency,
+    pub source: Source,
+    pub amount: isize,
+}
+
+/// The income and expenses from a single source.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Balance {
+    pub income: isize,
+    pub expenses: isize,
+}
+
+impl Balance {
+    pub fn net(&self) -> isize {
+        self.income - self.expenses
+    }
+}
+
+/// A record of every change in political capital
+/// and research points over the planning cycle.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Ledger {
+    /// Entries for the current cycle.
+    pub entries: Vec<Entry>,
+
+    /// Entries for the previous cycle.
+    pub previous: Vec<Entry>,
+}
+
+impl Ledger {
+    pub fn record(&mut self, currency: Currency, source: Source, amount: isize) {
+        if amount != 0 {
+            self.entries.push(Entry {
+                currency,
+                source,
+                amount,
+            });
+        }
+    }
+
+    /// Start a new cycle, setting aside the current one's entries.
+    pub fn close_cycle(&mut self) {
+        self.previous = std::mem::take(&mut self.entries);
+    }
+
+    /// The current cycle's income and expenses, by source.
+    pub fn breakdown(&self, currency: Currency) -> BTreeMap<Source, Balance> {
+        breakdown(&self.entries, currency)
+    }
+
+    /// The previous cycle's income and expenses, by source.
+    pub fn previous_breakdown(&self, currency: Currency) -> BTreeMap<Source, Balance> {
+        breakdown(&self.previous, currency)
+    }
+
+    pub fn income(&self, currency: Currency) -> isize {
+        self.breakdown(currency).values().map(|b| b.income).sum()
+    }
+
+    pub fn expenses(&self, currency: Currency) -> isize {
+        self.breakdown(currency).values().map(|b| b.expenses).sum()
+    }
+}
+
+fn breakdown(entries: &[Entry], currency: Currency) -> BTreeMap<Source, Balance> {
+    let mut breakdown: BTreeMap<Source, Balance> = BTreeMap::new();
+    for entry in entries.iter().filter(|e| e.currency == currency) {
+        let balance = breakdown.entry(entry.source).or_default();
+        if entry.amount > 0 {
+            balance.income += entry.amount;
+        } else {
+            balance.expenses -= entry.amount;
+        }
+    }
+    breakdown
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        events::{Effect, PlayerVariable},
+        state::State,
+    };
+
+    #[test]
+    fn test_ledger() {
+        let mut state = State::default();
+        let start = state.political_capital;
+        state.change_political_capital(10, Source::Request);
+        state.change_political_capital(-4, Source::ProjectCost);
+        state.change_political_capital(-3, Source::ProjectCost);
+        state.change_political_capital(2, Source::Refund);
+        state.change_political_capital(0, Source::Outlook);
+        Effect::PlayerVariable(PlayerVariable::PoliticalCapital, 5.).apply(&mut state, None);
+        Effect::PlayerVariable(PlayerVariable::ResearchPoints, 3.).apply(&mut state, None);
+
+        let ledger = &state.ledger;
+        assert_eq!(ledger.entries.len(), 6);
+        let breakdown = ledger.breakdown(Currency::PoliticalCapital);
+        assert_eq!(breakdown.get(&Source::Outlook), None);
+        assert_eq!(
+            breakdown[&Source::ProjectCost],
+            Balance {
+                income: 0,
+                expenses: 7
+            }
+        );
+        assert_eq!(breakdown[&Source::Event].net(), 5);
+        assert_eq!(ledger.income(Currency::PoliticalCapital), 17);
+        assert_eq!(ledger.expenses(Currency::PoliticalCapital), 7);
+        let net: isize = breakdown.values().map(Balance::net).sum();
+        assert_eq!(state.political_capital - start, net);
+        assert_eq!(ledger.income(Currency::Research), 3);
+
+        state.ledger.close_cycle();
+        assert!(state.ledger.entries.is_empty());
+        assert_eq!(state.ledger.previous.len(), 6);
+        assert_eq!(state.ledger.income(Currency::PoliticalCapital), 0);
+        assert_eq!(
+            state.ledger.previous_breakdown(Currency::PoliticalCapital),
+            breakdown
+        );
+    }
+}
@@ -1,18 +1,50 @@
+mod agendas;
+mod carbon;
+mod construction;
 mod diff;
+mod disasters;
+mod elections;
 mod events;
 pub mod flavor;
 mod industries;
 mod kinds;
+mod land;
+mod ledger;
+mod migration;
 mod npcs;
+mod parliament;
 mod production;
 mod projects;
 mod regions;
 mod state;
+mod synergies;
+mod tech;
+mod tipping;
 mod util;
 mod world;
 
+pub use agendas::{
+    AgendaItem,
+    AgendaRequest,
+    Agendas,
+    ClosedRequests,
+    Demand as NPCDemand,
+    DemandKind as NPCDemandKind,
+    REQUEST_COOLDOWN,
+};
+pub use carbon::{BUDGET_1_5C, BUDGET_2C, CarbonBudget};
+pub use construction::{Risk as ConstructionRisk, Setback};
 pub use diff::{Change, Diff};
+pub use disasters::{DisasterImpact, Hazard};
+pub use elections::{
+    CAMPAIGN_COST,
+    ElectionResult,
+    Elections,
+    FactionResult as ElectionFactionResult,
+    MAX_CAMPAIGNS,
+};
 pub use events::{
+    Branch as StorylineBranch,
     Comparator,
     Condition,
     ConditionKind,
@@ -29,13 +61,26 @@ pub use events::{
     Probability,
     RegionFlag,
     Request as NPCRequest,
+    Stage as StorylineStage,
+    Storyline,
+    StorylineProgress,
     WorldVariable,
     mean_demand_outlook_change,
     mean_income_outlook_change,
 };
 pub use industries::Industry;
 pub use kinds::*;
-pub use npcs::{NPC, NPCRelation};
+pub use land::Land;
+pub use ledger::{Balance, Currency, Entry as LedgerEntry, Ledger, Source as LedgerSource};
+pub use npcs::{
+    Ability as NPCAbility,
+    AbilityKind as NPCAbilityKind,
+    Memory as NPCMemory,
+    NPC,
+    NPCRelation,
+    Reason as RelationshipReason,
+};
+pub use parliament::{FactionVote, Vote, VoteResult};
 pub use production::{Process, ProcessFeature};
 pub use projects::{
     Cost,
@@ -44,12 +89,30 @@ pub use projects::{
     Group,
     Outcome,
     Project,
+    Repeal,
     Status,
+    Target as ProjectTarget,
+    TargetKind as ProjectTargetKind,
     Type as ProjectType,
     Upgrade,
+    Upkeep,
+    UpkeepKind,
 };
-pub use regions::{Income, Latitude, Region};
+pub use regions::{ClimateRisks, Income, Latitude, Region};
 pub use state::{Emissions, ResolvedEvent, State, Update};
+pub use synergies::{
+    Interaction as SynergyInteraction,
+    InteractionKind as SynergyInteractionKind,
+    Synergy,
+};
+pub use tech::{
+    Dependency as ProjectDependency,
+    DependencyKind as ProjectDependencyKind,
+    Prerequisite,
+    PrerequisiteKind,
+    ProjectGraph,
+};
+pub use tipping::{TippingPoint, TippingProgress};
 pub use util::*;
 pub use world::World;
 
@@ -0,0 +1,199 @@
+use std::collections::BTreeMap;
+
+use crate::{Collection, Id, regions::Region};
+
+/// How much closed borders reduce migration.
+pub(crate) const CLOSED_BORDERS_MULTIPLIER: f32 = 0.5;
+
+/// Share of a region's population that migrates per year
+/// per point of attraction difference with the average destination.
+const MIGRATION_RATE: f32 = 0.01;
+
+/// Maximum share of a region's population
+/// that can leave in a single year.
+const MAX_EMIGRATION: f32 = 0.05;
+
+/// How much each point of habitability attracts migrants.
+const HABITABILITY_PULL: f32 = 0.1;
+
+/// How much each income level attracts migrants.
+const INCOME_PULL: f32 = 0.1;
+
+/// How much outlook changes per share of population
+/// that leaves or arrives. Losing people is demoralizing
+/// and absorbing them is a strain.
+const MIGRATION_OUTLOOK: f32 = 20.;
+
+/// Share of a region's population leaving in a year
+/// above which the migration is reported.
+const SIGNIFICANT_EMIGRATION: f32 = 0.005;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationFlow {
+    pub from: Id,
+    pub to: Id,
+    pub amount: f32,
+}
+
+/// How attractive a region is to migrants.
+fn attraction(region: &Region, sea_level_rise: f32) -> f32 {
+    region.habitability(sea_level_rise) * HABITABILITY_PULL
+        + region.adjusted_income() * INCOME_PULL
+}
+
+/// People move from less to more attractive regions,
+/// in proportion to the difference in attraction.
+/// Seceded regions neither send nor receive migrants.
+pub fn migration_flows(
+    regions: &Collection<Region>,
+    sea_level_rise: f32,
+    closed_borders: bool,
+) -> Vec<MigrationFlow> {
+    let modifier = if closed_borders {
+        CLOSED_BORDERS_MULTIPLIER
+    } else {
+        1.
+    };
+    let open: Vec<(&Region, f32)> = regions
+        .iter()
+        .filter(|region| !region.seceded)
+        .map(|region| (region, attraction(region, sea_level_rise)))
+        .collect();
+    let destinations = (open.len() as f32 - 1.).max(1.);
+
+    let mut flows = vec![];
+    for (from, from_attraction) in &open {
+        let start = flows.len();
+        for (to, to_attraction) in &open {
+            let diff = to_attraction - from_attraction;
+            if from.id != to.id && diff > 0. {
+                flows.push(MigrationFlow {
+                    from: from.id,
+                    to: to.id,
+                    amount: from.population * MIGRATION_RATE * modifier * diff / destinations,
+                });
+            }
+        }
+
+        // Cap how many people can leave in one year.
+        let total: f32 = flows[start..].iter().map(|flow| flow.amount).sum();
+        let max = from.population * MAX_EMIGRATION;
+        if total > max {
+            for flow in &mut flows[start..] {
+                flow.amount *= max / total;
+            }
+        }
+    }
+    flows
+}
+
+/// Move people between regions, updating
+/// the outlook of both the origin and destination.
+pub fn migrate(
+    regions: &mut Collection<Region>,
+    sea_level_rise: f32,
+    closed_borders: bool,
+) -> Vec<MigrationFlow> {
+    let flows = migration_flows(regions, sea_level_rise, closed_borders);
+    for flow in &flows {
+        let from = &mut regions[&flow.from];
+        from.outlook -= flow.amount / from.population * MIGRATION_OUTLOOK;
+        from.population -= flow.amount;
+
+        let to = &mut regions[&flow.to];
+        to.outlook -= flow.amount / to.population * MIGRATION_OUTLOOK;
+        to.population += flow.amount;
+    }
+    flows
+}
+
+/// Regions that lost a significant share of their population,
+/// with their main destination and the total number of emigrants.
+pub fn significant_emigration(
+    flows: &[MigrationFlow],
+    regions: &Collection<Region>,
+) -> Vec<(Id, Id, f32)> {
+    let mut by_origin: BTreeMap<Id, (Id, f32, f32)> = BTreeMap::new();
+    for flow in flows {
+        let (to, largest, total) = by_origin.entry(flow.from).or_insert((flow.to, 0., 0.));
+        if flow.amount > *largest {
+            *to = flow.to;
+            *largest = flow.amount;
+        }
+        *total += flow.amount;
+    }
+    by_origin
+        .into_iter()
+        .filter(|(from, (_, _, total))| {
+            total / regions[from].population >= SIGNIFICANT_EMIGRATION
+        })
+        .map(|(from, (to, _, total))| (from, to, total))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::regions::Income;
+
+    fn gen_regions() -> Collection<Region> {
+        (0..3)
+            .map(|i| Region {
+                id: Id::new_v4(),
+                name: format!("Region {i}"),
+                population: 1000.,
+                base_habitability: 10.,
+                outlook: 10.,
+                precip_lo: 10.,
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_migration_flows() {
+        let mut regions = gen_regions();
+        let ids: Vec<Id> = regions.iter().map(|r| r.id).collect();
+
+        // Equally attractive regions have no migration.
+        assert!(migration_flows(&regions, 0., false).is_empty());
+
+        // People leave the damaged region for the others.
+        regions[&ids[0]].damage(5.);
+        let flows = migration_flows(&regions, 0., false);
+        assert_eq!(flows.len(), 2);
+        assert!(flows.iter().all(|flow| flow.from == ids[0]));
+
+        // And are also drawn to wealthier regions.
+        regions[&ids[2]].income = Income::High;
+        let flows = migration_flows(&regions, 0., false);
+        assert!(flows.iter().any(|flow| flow.from == ids[1] && flow.to == ids[2]));
+
+        // Closed borders reduce migration.
+        let open: f32 = migration_flows(&regions, 0., false).iter().map(|f| f.amount).sum();
+        let closed: f32 = migration_flows(&regions, 0., true).iter().map(|f| f.amount).sum();
+        assert_eq!(closed, open * CLOSED_BORDERS_MULTIPLIER);
+
+        // Seceded regions are closed off.
+        regions[&ids[2]].seceded = true;
+        let flows = migration_flows(&regions, 0., false);
+        assert!(flows.iter().all(|flow| flow.to != ids[2] && flow.from != ids[2]));
+    }
+
+    #[test]
+    fn test_migrate() {
+        let mut regions = gen_regions();
+        let ids: Vec<Id> = regions.iter().map(|r| r.id).collect();
+        regions[&ids[0]].damage(10.);
+
+        let start_pop: f32 = regions.iter().map(|r| r.population).sum();
+        let flows = migrate(&mut regions, 0., false);
+        let end_pop: f32 = regions.iter().map(|r| r.population).sum();
+        assert!(!flows.is_empty());
+        assert!((start_pop - end_pop).abs() < 1e-3);
+
+        assert!(regions[&ids[0]].population < 1000.);
+        assert!(regions[&ids[1]].population > 1000.);
+        assert!(regions.iter().all(|r| r.outlook < 10.));
+    }
+}
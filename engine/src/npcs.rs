@@ -1,17 +1,41 @@
 use std::fmt::Display;
 
 use crate::{
+    agendas::{AgendaItem, Demand},
     flavor::NPCFlavor,
-    projects::Project,
+    projects::{Group, Project},
+    regions::Income,
     Collection,
     HasId,
     Id,
 };
 use serde::{Deserialize, Serialize};
-use strum::{Display, EnumIter, EnumString, IntoStaticStr};
+use strum::{
+    Display,
+    EnumDiscriminants,
+    EnumIter,
+    EnumString,
+    IntoStaticStr,
+};
 
 pub const RELATIONSHIP_CHANGE_AMOUNT: f32 = 0.5;
 
+/// The relationship NPCs drift back towards.
+pub const NEUTRAL_RELATIONSHIP: f32 = 3.;
+
+/// At or above this NPCs are allies,
+/// at or below this they're nemeses.
+pub const ALLY_RELATIONSHIP: f32 = 5.;
+pub const NEMESIS_RELATIONSHIP: f32 = 1.;
+
+/// How much of a relationship's distance
+/// from neutral fades each year.
+pub const RELATIONSHIP_FADE: f32 = 0.02;
+
+/// How many years NPCs remember what the player did.
+/// Older changes become part of their general view.
+pub const MEMORY_YEARS: usize = 20;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct NPC {
     pub id: Id,
@@ -22,6 +46,128 @@ pub struct NPC {
     pub flavor: NPCFlavor,
     pub name: String,
     pub extra_seats: usize,
+
+    /// Abilities that are active while this NPC is an ally.
+    #[serde(default)]
+    pub abilities: Vec<Ability>,
+
+    /// What this NPC will ask the player for,
+    /// in order of priority.
+    #[serde(default)]
+    pub agenda: Vec<AgendaItem>,
+
+    /// Recent changes to the relationship.
+    #[serde(default)]
+    pub memories: Vec<Memory>,
+}
+
+impl Default for NPC {
+    fn default() -> Self {
+        Self {
+            id: Id::new_v4(),
+            relationship: 3.,
+            locked: false,
+            support: 100.,
+            seats: 0.,
+            flavor: NPCFlavor::default(),
+            name: "New NPC".into(),
+            extra_seats: 0,
+            abilities: vec![],
+            agenda: vec![],
+            memories: vec![],
+        }
+    }
+}
+
+/// Why a relationship changed.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub enum Reason {
+    /// A project they support or oppose was completed.
+    ProjectCompleted(Id),
+
+    /// A project they support or oppose was stopped.
+    ProjectStopped(Id),
+
+    /// On top of the policy being stopped.
+    PolicyRepealed(Id),
+
+    ProcessBanned(Id),
+    ProcessUnbanned(Id),
+    ProcessPromoted(Id),
+    ProcessUnpromoted(Id),
+
+    RequestFulfilled(Demand),
+    RequestIgnored(Demand),
+
+    /// An event or project effect.
+    Event,
+
+    /// Their general view of the player: where they
+    /// started and whatever they've since forgotten.
+    /// This is never remembered, only used
+    /// to explain the relationship.
+    Standing,
+}
+
+/// A change to a relationship,
+/// which fades along with the relationship.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Memory {
+    pub year: usize,
+    pub reason: Reason,
+    pub change: f32,
+}
+
+#[derive(
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Debug,
+    Clone,
+    EnumDiscriminants,
+)]
+#[strum_discriminants(derive(
+    EnumIter,
+    EnumString,
+    IntoStaticStr,
+    Display
+))]
+#[strum_discriminants(name(AbilityKind))]
+pub enum Ability {
+    /// Multiply the cost of (non-policy)
+    /// projects in this group.
+    ProjectCostModifier(Group, f32),
+
+    /// How much the outlook of regions at this
+    /// income level recovers each year,
+    /// instead of the usual amount.
+    RegionOutlookRecovery(Income, f32),
+
+    /// Reduce the political capital
+    /// cost of research points.
+    ResearchPointDiscount(usize),
+
+    /// Don't lose when outlook drops below zero.
+    PreventOutlookGameOver,
+}
+
+impl Ability {
+    pub fn from_kind(kind: AbilityKind) -> Self {
+        match kind {
+            AbilityKind::ProjectCostModifier => {
+                Self::ProjectCostModifier(Group::Other, 0.75)
+            }
+            AbilityKind::RegionOutlookRecovery => {
+                Self::RegionOutlookRecovery(Income::Low, 0.3)
+            }
+            AbilityKind::ResearchPointDiscount => {
+                Self::ResearchPointDiscount(1)
+            }
+            AbilityKind::PreventOutlookGameOver => {
+                Self::PreventOutlookGameOver
+            }
+        }
+    }
 }
 
 impl HasId for NPC {
@@ -40,8 +186,8 @@ impl Display for NPC {
 }
 
 impl NPC {
-    // NPCs are hardcoded cause it's a bit more complicated
-    // to make them editable.
+    /// The default NPCs, used for worlds
+    /// that don't define their own.
     pub fn load() -> Collection<Self> {
         serde_json::from_str(include_str!(
             "../assets/npcs.json"
@@ -54,21 +200,91 @@ impl NPC {
     }
 
     pub fn relation(&self) -> NPCRelation {
-        if self.relationship >= 5. {
+        if self.relationship >= ALLY_RELATIONSHIP {
             NPCRelation::Ally
-        } else if self.relationship <= 1. {
+        } else if self.relationship <= NEMESIS_RELATIONSHIP {
             NPCRelation::Nemesis
         } else {
             NPCRelation::Neutral
         }
     }
 
+    /// Change the relationship and remember why.
+    pub fn change_relationship(
+        &mut self,
+        change: f32,
+        reason: Reason,
+        year: usize,
+    ) {
+        self.relationship += change;
+        let memory = self.memories.iter_mut().find(|memory| {
+            memory.year == year && memory.reason == reason
+        });
+        match memory {
+            Some(memory) => memory.change += change,
+            None => self.memories.push(Memory {
+                year,
+                reason,
+                change,
+            }),
+        }
+    }
+
+    /// Drift back towards a neutral relationship
+    /// and forget anything that's too long ago.
+    /// Fading alone never turns an ally or nemesis neutral.
+    pub fn fade_relationship(&mut self, year: usize) {
+        let keep = 1. - RELATIONSHIP_FADE;
+        let faded = NEUTRAL_RELATIONSHIP
+            + (self.relationship - NEUTRAL_RELATIONSHIP) * keep;
+        self.relationship = match self.relation() {
+            NPCRelation::Ally => faded.max(ALLY_RELATIONSHIP),
+            NPCRelation::Nemesis => {
+                faded.min(NEMESIS_RELATIONSHIP)
+            }
+            NPCRelation::Neutral => faded,
+        };
+        for memory in &mut self.memories {
+            memory.change *= keep;
+        }
+        self.memories.retain(|memory| {
+            memory.change != 0. && year < memory.year + MEMORY_YEARS
+        });
+    }
+
+    /// What the current relationship is made up of,
+    /// as how much each reason adds to or takes away
+    /// from a neutral relationship, most recent first.
+    /// These add up to the relationship.
+    pub fn relationship_reasons(&self) -> Vec<(Reason, f32)> {
+        let mut reasons: Vec<(Reason, f32)> = vec![];
+        for memory in self.memories.iter().rev() {
+            match reasons
+                .iter_mut()
+                .find(|(reason, _)| *reason == memory.reason)
+            {
+                Some((_, change)) => *change += memory.change,
+                None => reasons
+                    .push((memory.reason.clone(), memory.change)),
+            }
+        }
+        reasons.retain(|(_, change)| change.abs() > 1e-3);
+        let remembered: f32 =
+            reasons.iter().map(|(_, change)| change).sum();
+        let standing =
+            self.relationship - NEUTRAL_RELATIONSHIP - remembered;
+        if standing.abs() > 1e-3 {
+            reasons.push((Reason::Standing, standing));
+        }
+        reasons
+    }
+
     pub fn relationship_name(&self) -> &'static str {
-        if self.relationship >= 5. {
+        if self.relationship >= ALLY_RELATIONSHIP {
             "Ally"
         } else if self.relationship >= 4. {
             "Friendly"
-        } else if self.relationship <= 1. {
+        } else if self.relationship <= NEMESIS_RELATIONSHIP {
             "Nemesis"
         } else {
             "Neutral"
@@ -99,13 +315,61 @@ impl Collection<NPC> {
         self.iter().filter(|npc| !npc.locked)
     }
 
-    pub fn is_ally(&self, name: &'static str) -> bool {
-        let npc = self.iter().find(|n| n.name == name);
-        if let Some(npc) = npc {
-            npc.is_ally()
-        } else {
-            false
-        }
+    /// Abilities of all current allies.
+    pub fn ally_abilities(
+        &self,
+    ) -> impl Iterator<Item = &Ability> {
+        self.iter()
+            .filter(|npc| npc.is_ally())
+            .flat_map(|npc| npc.abilities.iter())
+    }
+
+    /// Combined cost modifier for projects
+    /// in this group from ally abilities.
+    pub fn project_cost_modifier(&self, group: Group) -> f32 {
+        self.ally_abilities()
+            .filter_map(|ability| match ability {
+                Ability::ProjectCostModifier(g, modifier)
+                    if *g == group =>
+                {
+                    Some(*modifier)
+                }
+                _ => None,
+            })
+            .product()
+    }
+
+    /// Outlook recovery for regions at this income level,
+    /// if an ally changes it.
+    pub fn region_outlook_recovery(
+        &self,
+        income: Income,
+    ) -> Option<f32> {
+        self.ally_abilities()
+            .filter_map(|ability| match ability {
+                Ability::RegionOutlookRecovery(inc, amount)
+                    if *inc == income =>
+                {
+                    Some(*amount)
+                }
+                _ => None,
+            })
+            .reduce(f32::max)
+    }
+
+    pub fn research_point_discount(&self) -> usize {
+        self.ally_abilities()
+            .map(|ability| match ability {
+                Ability::ResearchPointDiscount(amount) => *amount,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    pub fn prevents_outlook_game_over(&self) -> bool {
+        self.ally_abilities().any(|ability| {
+            matches!(ability, Ability::PreventOutlookGameOver)
+        })
     }
 
     pub fn update_seats(
@@ -159,3 +423,102 @@ impl Collection<NPC> {
             .sum()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ally_abilities() {
+        let mut npcs = NPC::load();
+        let id = npcs
+            .iter()
+            .find(|npc| {
+                npc.abilities.iter().any(|ability| {
+                    matches!(
+                        ability,
+                        Ability::ProjectCostModifier(
+                            Group::Population,
+                            _
+                        )
+                    )
+                })
+            })
+            .unwrap()
+            .id;
+
+        npcs[&id].relationship = 3.;
+        assert_eq!(
+            npcs.project_cost_modifier(Group::Population),
+            1.
+        );
+
+        npcs[&id].relationship = 5.;
+        assert!(
+            npcs.project_cost_modifier(Group::Population) < 1.
+        );
+    }
+
+    #[test]
+    fn test_relationship_memory() {
+        let mut npc = NPC::default();
+        let (a, b) = (Id::new_v4(), Id::new_v4());
+        let year = 2030;
+        npc.change_relationship(
+            1.,
+            Reason::ProjectCompleted(a),
+            year,
+        );
+        npc.change_relationship(-0.5, Reason::Event, year);
+        npc.change_relationship(0.5, Reason::Event, year);
+        npc.change_relationship(
+            0.5,
+            Reason::ProjectCompleted(b),
+            year + 2,
+        );
+        assert_eq!(npc.relationship, 4.5);
+        assert_eq!(
+            npc.relationship_reasons(),
+            vec![
+                (Reason::ProjectCompleted(b), 0.5),
+                (Reason::ProjectCompleted(a), 1.),
+            ]
+        );
+
+        // Relationships and memories fade together.
+        npc.fade_relationship(year + 3);
+        assert!(npc.relationship < 4.5);
+        let total: f32 = npc
+            .relationship_reasons()
+            .iter()
+            .map(|(_, change)| change)
+            .sum();
+        let relationship = NEUTRAL_RELATIONSHIP + total;
+        assert!((relationship - npc.relationship).abs() < 1e-4);
+
+        // Forgotten changes become part of their general view.
+        npc.fade_relationship(year + MEMORY_YEARS);
+        let reasons = npc.relationship_reasons();
+        assert_eq!(reasons.len(), 2);
+        assert_eq!(reasons[1].0, Reason::Standing);
+        assert!(reasons[1].1 > 0.);
+    }
+
+    #[test]
+    fn test_fade_keeps_relation() {
+        let mut npc = NPC::load().by_idx(0).clone();
+        npc.relationship = ALLY_RELATIONSHIP;
+        npc.fade_relationship(2022);
+        assert!(npc.is_ally());
+
+        npc.relationship = NEMESIS_RELATIONSHIP;
+        npc.fade_relationship(2022);
+        assert_eq!(npc.relation(), NPCRelation::Nemesis);
+
+        // Stronger feelings still fade, up to the threshold.
+        npc.relationship = 6.;
+        npc.fade_relationship(2022);
+        assert!(npc.relationship < 6.);
+        assert!(npc.is_ally());
+    }
+}
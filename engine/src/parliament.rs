@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Collection,
+    Id,
+    events::Effect,
+    npcs::{NPC, NPCRelation},
+    projects::Project,
+};
+
+/// Political capital it costs to bargain
+/// for the entirety of parliament.
+const BARGAIN_COST: f32 = 100.;
+
+/// How strongly a faction has to feel about
+/// a policy to vote for or against it.
+const VOTE_THRESHOLD: f32 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Vote {
+    For,
+    Against,
+    Abstain,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FactionVote {
+    pub npc_id: Id,
+    pub seats: f32,
+    pub vote: Vote,
+
+    /// If this faction was won over by bargaining.
+    pub bargained: bool,
+
+    /// The political capital needed to win this faction over,
+    /// if they're willing to bargain.
+    pub bargain_cost: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoteResult {
+    pub factions: Vec<FactionVote>,
+
+    /// Share of seats needed to pass,
+    /// or zero if the vote is a formality.
+    pub required_majority: f32,
+}
+impl VoteResult {
+    /// Share of seats voting this way.
+    pub fn seats(&self, vote: Vote) -> f32 {
+        self.factions
+            .iter()
+            .filter(|faction| faction.vote == vote)
+            .map(|faction| faction.seats)
+            .sum()
+    }
+
+    pub fn passes(&self) -> bool {
+        self.required_majority <= 0.
+            || self.seats(Vote::For) > self.required_majority
+    }
+}
+
+/// How a faction feels about a project,
+/// where positive is in favor.
+fn stance(npc: &NPC, project: &Project) -> f32 {
+    let mut score = 0.;
+    if project.supporters.contains(&npc.id) {
+        score += 1.;
+    }
+    if project.opposers.contains(&npc.id) {
+        score -= 1.;
+    }
+    for effect in &project.effects {
+        if let Effect::NPCRelationship(id, change) = effect
+            && *id == npc.id
+        {
+            score += change.signum() * 0.5;
+        }
+    }
+
+    // A neutral relationship is 3.
+    score + (npc.relationship - 3.) / 4.
+}
+
+/// Simulate a parliamentary vote on the project.
+/// Allies always vote with the player. Other factions
+/// vote based on their stance on the project and their
+/// relationship with the player, but can be won over
+/// (bargained with) by amending the project, at a cost
+/// of political capital. Nemeses won't bargain.
+pub fn simulate_vote(
+    project: &Project,
+    npcs: &Collection<NPC>,
+    bargained: &[Id],
+    suspended: bool,
+) -> VoteResult {
+    let factions = npcs
+        .unlocked()
+        .map(|npc| {
+            let score = stance(npc, project);
+            let bargained = bargained.contains(&npc.id);
+            let vote = if npc.is_ally() || bargained || score >= VOTE_THRESHOLD {
+                Vote::For
+            } else if score <= -VOTE_THRESHOLD {
+                Vote::Against
+            } else {
+                Vote::Abstain
+            };
+            let bargain_cost = if vote == Vote::For
+                || npc.relation() == NPCRelation::Nemesis
+            {
+                None
+            } else {
+                let cost = (1. - score) * npc.seats * BARGAIN_COST;
+                Some(cost.ceil().max(1.) as usize)
+            };
+            FactionVote {
+                npc_id: npc.id,
+                seats: npc.seats,
+                vote,
+                bargained,
+                bargain_cost,
+            }
+        })
+        .collect();
+
+    VoteResult {
+        factions,
+        required_majority: if suspended {
+            0.
+        } else {
+            project.required_majority
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn gen_npcs() -> Collection<NPC> {
+        (0..4)
+            .map(|i| NPC {
+                name: format!("NPC {i}"),
+                seats: 0.25,
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_vote() {
+        let mut npcs = gen_npcs();
+        let ids: Vec<Id> = npcs.iter().map(|npc| npc.id).collect();
+        let project = Project {
+            supporters: vec![ids[0]],
+            opposers: vec![ids[1], ids[2]],
+            required_majority: 0.5,
+            ..Default::default()
+        };
+
+        let result = simulate_vote(&project, &npcs, &[], false);
+        assert_eq!(result.seats(Vote::For), 0.25);
+        assert_eq!(result.seats(Vote::Against), 0.5);
+        assert_eq!(result.seats(Vote::Abstain), 0.25);
+        assert!(!result.passes());
+
+        // Suspended parliament always passes.
+        let result = simulate_vote(&project, &npcs, &[], true);
+        assert!(result.passes());
+
+        // Allies vote with the player, even if opposed.
+        npcs[&ids[1]].relationship = 5.;
+        let result = simulate_vote(&project, &npcs, &[], false);
+        assert_eq!(result.seats(Vote::For), 0.5);
+        assert!(!result.passes());
+
+        // Bargaining wins over a faction.
+        let result = simulate_vote(&project, &npcs, &[ids[3]], false);
+        assert!(result.factions[3].bargained);
+        assert!(result.passes());
+    }
+
+    #[test]
+    fn test_nemeses_dont_bargain() {
+        let mut npcs = gen_npcs();
+        let id = npcs.first().id;
+        let project = Project {
+            opposers: vec![id],
+            required_majority: 0.5,
+            ..Default::default()
+        };
+
+        let result = simulate_vote(&project, &npcs, &[], false);
+        assert!(result.factions[0].bargain_cost.is_some());
+
+        npcs[&id].relationship = 1.;
+        let result = simulate_vote(&project, &npcs, &[], false);
+        assert_eq!(result.factions[0].bargain_cost, None);
+    }
+}
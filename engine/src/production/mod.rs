@@ -23,6 +23,7 @@ pub fn produce(
     demand: (&ResourceMap, &FeedstockMap),
     resources: &ResourceMap,
     feedstocks: &FeedstockMap,
+    land: &OutputMap,
 ) -> (
     BTreeMap<Id, f32>,
     OutputMap,
@@ -33,7 +34,7 @@ pub fn produce(
     // Calculate the output
     let (produced, consumed_r, consumed_f, byproducts) =
         planner::calculate_production(
-            orders, demand, resources, feedstocks,
+            orders, demand, resources, feedstocks, land,
         );
 
     // Calculate production per output type
@@ -206,12 +206,16 @@ impl IndexMut<Output> for Outputs {
     }
 }
 
+/// Land is further limited to each output's budget,
+/// see `Land::budgets`.
 pub fn calculate_production(
     orders: &[ProductionOrder],
     demand: (&ResourceMap, &FeedstockMap),
     starting_resources: &ResourceMap,
     starting_feedstocks: &FeedstockMap,
+    land_budgets: &OutputMap,
 ) -> (Vec<f32>, ResourceMap, FeedstockMap, ByproductMap) {
+    let mut land_budgets = *land_budgets;
     let mut resources = *starting_resources;
     let mut feedstocks = *starting_feedstocks;
     let mut produced_byproducts: ByproductMap = byproducts!();
@@ -224,7 +228,7 @@ pub fn calculate_production(
 
     let mut continue_production = true;
     while continue_production {
-        for (_, order_idxs) in orders_by_output.items_mut() {
+        for (output, order_idxs) in orders_by_output.items_mut() {
             if order_idxs.is_empty() {
                 continue;
             }
@@ -234,12 +238,18 @@ pub fn calculate_production(
             // Ok to unwrap b/c we check if `orders` is empty
             let order_idx = order_idxs.pop().unwrap();
 
+            let land = resources.land;
+            let budget = land.min(land_budgets[output]);
+            resources.land = budget;
             let amount = produce_amount(
                 &orders[order_idx],
                 &mut resources,
                 &mut feedstocks,
                 &mut produced_byproducts,
             );
+            let land_used = budget - resources.land;
+            land_budgets[output] -= land_used;
+            resources.land = land - land_used;
 
             produced[order_idx] = amount;
         }
@@ -3,7 +3,7 @@ use crate::{
     Collection, HasId, Id,
     flavor::ProcessFlavor,
     kinds::{ByproductMap, Feedstock, FeedstockMap, Output, OutputMap, ResourceMap},
-    npcs::RELATIONSHIP_CHANGE_AMOUNT,
+    npcs::{RELATIONSHIP_CHANGE_AMOUNT, Reason},
 };
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
@@ -126,10 +126,15 @@ impl Process {
         self.feedstock.1 / (1. + self.output_modifier)
     }
 
+    /// Extinction rate contribution from
+    /// everything but land use.
+    pub fn biodiversity_pressure(&self) -> f32 {
+        self.adj_byproducts().biodiversity / 3e16 * 100.
+    }
+
     pub fn extinction_rate(&self, starting_land: f32) -> f32 {
-        let pressure = self.adj_byproducts().biodiversity;
         let land = self.adj_resources().land;
-        (pressure / 3e16 + land / starting_land) * 100.
+        self.biodiversity_pressure() + land / starting_land * 100.
     }
 
     pub fn max_share(&self, output_demand: &OutputMap, feedstocks: &FeedstockMap) -> usize {
@@ -165,32 +170,36 @@ impl Process {
             self.mix_share += change as usize;
         }
 
-        let (support_change, oppose_change) = if !was_banned && self.is_banned() {
+        let (support_change, oppose_change, reason) = if !was_banned && self.is_banned() {
             // Ban
-            (-1., 1.)
+            (-1., 1., Reason::ProcessBanned(self.id))
         } else if was_banned && !self.is_banned() {
             // Unban
-            (1., -1.)
+            (1., -1., Reason::ProcessUnbanned(self.id))
         } else if was_promoted && !self.is_promoted() {
             // Unpromote
-            (-1., 1.)
+            (-1., 1., Reason::ProcessUnpromoted(self.id))
         } else if !was_promoted && self.is_promoted() {
             // Promote
-            (1., -1.)
+            (1., -1., Reason::ProcessPromoted(self.id))
         } else {
-            (0., 0.)
+            return ProcessChanges::default();
         };
 
         let mut changes = ProcessChanges::default();
         for npc_id in &self.supporters {
-            changes
-                .relationships
-                .push((*npc_id, support_change * RELATIONSHIP_CHANGE_AMOUNT));
+            changes.relationships.push((
+                *npc_id,
+                support_change * RELATIONSHIP_CHANGE_AMOUNT,
+                reason.clone(),
+            ));
         }
         for npc_id in &self.opposers {
-            changes
-                .relationships
-                .push((*npc_id, oppose_change * RELATIONSHIP_CHANGE_AMOUNT));
+            changes.relationships.push((
+                *npc_id,
+                oppose_change * RELATIONSHIP_CHANGE_AMOUNT,
+                reason.clone(),
+            ));
         }
         changes
     }
@@ -198,7 +207,7 @@ impl Process {
 
 #[derive(Default)]
 pub struct ProcessChanges {
-    pub relationships: Vec<(Id, f32)>,
+    pub relationships: Vec<(Id, f32, Reason)>,
 }
 
 impl Collection<Process> {
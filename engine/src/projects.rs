@@ -1,14 +1,22 @@
 use crate::{
     Collection, HasId, Id,
-    events::{Effect, Probability},
+    construction::{FAILURE_LOSS, OVERRUN_COST, Risk, Setback},
+    events::{Effect, Probability, RegionFlag},
     flavor::ProjectFlavor,
-    kinds::{Output, OutputMap},
-    npcs::{NPC, NPCRelation, RELATIONSHIP_CHANGE_AMOUNT},
+    kinds::{Feedstock, Output, Resource},
+    npcs::{NPC, NPCRelation, RELATIONSHIP_CHANGE_AMOUNT, Reason},
+    regions::{Income, Latitude, Region},
+    state::State,
+    tech::Prerequisite,
 };
 use serde::{Deserialize, Serialize};
-use std::fmt::Display;
+use std::{collections::BTreeMap, fmt::Display};
 use strum::{Display, EnumDiscriminants, EnumIter, EnumString, IntoStaticStr};
 
+/// The smallest value an inverse factor divides by,
+/// so a factor at or below zero can't make a project free.
+const MIN_INVERSE_DENOMINATOR: f32 = 0.1;
+
 /// The project's status.
 #[derive(
     Display,
@@ -101,14 +109,106 @@ impl Default for Cost {
     }
 }
 
-/// A cost factor used to compute dynamic costs.
+/// A recurring cost paid every planning
+/// cycle while a project is online.
 #[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Debug, EnumDiscriminants)]
 #[strum_discriminants(derive(EnumIter, EnumString, IntoStaticStr, Display))]
+#[strum_discriminants(name(UpkeepKind))]
+pub enum Upkeep {
+    PoliticalCapital(usize),
+    Research(usize),
+}
+
+impl Upkeep {
+    pub fn amount(&self) -> usize {
+        match self {
+            Upkeep::PoliticalCapital(amount) | Upkeep::Research(amount) => *amount,
+        }
+    }
+}
+
+impl From<UpkeepKind> for Upkeep {
+    fn from(kind: UpkeepKind) -> Self {
+        match kind {
+            UpkeepKind::PoliticalCapital => Upkeep::PoliticalCapital(5),
+            UpkeepKind::Research => Upkeep::Research(1),
+        }
+    }
+}
+
+/// The political fallout of repealing a policy.
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Debug, Default)]
+pub struct Repeal {
+    /// Political capital it costs to repeal.
+    pub cost: usize,
+
+    /// How much the policy's supporters' relationships drop,
+    /// on top of losing the goodwill from passing it.
+    pub supporter_backlash: f32,
+
+    /// How much the policy's opposers' relationships improve,
+    /// on top of no longer holding it against the player.
+    pub opposer_approval: f32,
+
+    /// The change in outlook, usually negative.
+    pub outlook: f32,
+
+    /// How many years after passing the policy
+    /// is locked in and can't be repealed or downgraded.
+    pub lock_in: usize,
+}
+
+impl Repeal {
+    /// If repealing has any consequences at all.
+    pub fn has_consequences(&self) -> bool {
+        self.cost > 0
+            || self.supporter_backlash != 0.
+            || self.opposer_approval != 0.
+            || self.outlook != 0.
+    }
+}
+
+/// A cost factor used to compute dynamic costs.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumIter, EnumString, IntoStaticStr, Display))]
 #[strum_discriminants(name(FactorKind))]
 pub enum Factor {
     Time,
     Income,
     Output(Output),
+
+    /// Demand for a resource relative to what's available.
+    ResourcePressure(Resource),
+
+    /// How much of a feedstock's starting reserves
+    /// have been used up, from 0 to 1.
+    FeedstockScarcity(Feedstock),
+
+    /// The global temperature anomaly, in C.
+    Temperature,
+
+    /// Sea level rise, in meters.
+    SeaLevelRise,
+
+    /// How many regions are at or above this income level.
+    Regions(Income),
+
+    /// How many other projects in the same group are online.
+    GroupProjects,
+
+    /// A fixed value, for building up formulas.
+    Constant(f32),
+
+    /// The sum of these factors.
+    Sum(Vec<Factor>),
+
+    /// The product of these factors.
+    Product(Vec<Factor>),
+
+    /// One over this factor, e.g. to make
+    /// a project cheaper as something worsens.
+    /// At most ten times the base cost.
+    Inverse(Box<Factor>),
 }
 
 impl From<FactorKind> for Factor {
@@ -117,6 +217,114 @@ impl From<FactorKind> for Factor {
             FactorKind::Time => Factor::Time,
             FactorKind::Income => Factor::Income,
             FactorKind::Output => Factor::Output(Output::default()),
+            FactorKind::ResourcePressure => Factor::ResourcePressure(Resource::Land),
+            FactorKind::FeedstockScarcity => Factor::FeedstockScarcity(Feedstock::default()),
+            FactorKind::Temperature => Factor::Temperature,
+            FactorKind::SeaLevelRise => Factor::SeaLevelRise,
+            FactorKind::Regions => Factor::Regions(Income::default()),
+            FactorKind::GroupProjects => Factor::GroupProjects,
+            FactorKind::Constant => Factor::Constant(1.),
+            FactorKind::Sum => Factor::Sum(vec![]),
+            FactorKind::Product => Factor::Product(vec![]),
+            FactorKind::Inverse => Factor::Inverse(Box::new(Factor::Temperature)),
+        }
+    }
+}
+
+impl Factor {
+    /// The factor's current value for the given project.
+    pub fn value(&self, state: &State, project: &Project) -> f32 {
+        match self {
+            // Kind of arbitrarily choose 1980 as the starting point
+            Factor::Time => (state.world.year - 1980) as f32,
+            Factor::Income => 1. + state.world.regions.income_level(),
+            Factor::Output(output) => state.output_demand.total()[*output],
+            Factor::ResourcePressure(resource) => {
+                let available = state.resources.available[*resource];
+                if available > 0. {
+                    state.resource_demand.of(*resource) / available
+                } else {
+                    0.
+                }
+            }
+            Factor::FeedstockScarcity(feedstock) => {
+                let reserves = state.world.feedstock_reserves[*feedstock];
+                if reserves > 0. {
+                    (1. - state.feedstocks.available[*feedstock] / reserves).clamp(0., 1.)
+                } else {
+                    0.
+                }
+            }
+            Factor::Temperature => state.world.temperature,
+            Factor::SeaLevelRise => state.world.sea_level_rise,
+            Factor::Regions(income) => state
+                .world
+                .regions
+                .iter()
+                .filter(|region| region.income.level() >= income.level())
+                .count() as f32,
+            Factor::GroupProjects => state
+                .world
+                .projects
+                .iter()
+                .filter(|other| {
+                    other.id != project.id && other.group == project.group && other.is_online()
+                })
+                .count() as f32,
+            Factor::Constant(value) => *value,
+            Factor::Sum(factors) => factors.iter().map(|f| f.value(state, project)).sum(),
+            Factor::Product(factors) => factors.iter().map(|f| f.value(state, project)).product(),
+            Factor::Inverse(factor) => {
+                let value = factor.value(state, project);
+                1. / value.max(MIN_INVERSE_DENOMINATOR)
+            }
+        }
+    }
+}
+
+impl Cost {
+    /// The cost before any modifiers are applied.
+    pub fn base_amount(&self, state: &State, project: &Project) -> usize {
+        match self {
+            Cost::Fixed(c) => *c,
+            Cost::Dynamic(m, factor) => {
+                let c = m * factor.value(state, project);
+                c.round().max(0.) as usize
+            }
+        }
+    }
+}
+
+/// Which regions a project applies to.
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Debug, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumIter, EnumString, IntoStaticStr, Display))]
+#[strum_discriminants(name(TargetKind))]
+pub enum Target {
+    /// A region chosen by the player.
+    Region,
+    Income(Income),
+    Latitude(Latitude),
+    RegionFlag(RegionFlag),
+}
+
+impl From<TargetKind> for Target {
+    fn from(kind: TargetKind) -> Self {
+        match kind {
+            TargetKind::Region => Target::Region,
+            TargetKind::Income => Target::Income(Income::default()),
+            TargetKind::Latitude => Target::Latitude(Latitude::default()),
+            TargetKind::RegionFlag => Target::RegionFlag(RegionFlag::Protests),
+        }
+    }
+}
+
+impl Target {
+    pub fn matches(&self, region: &Region, chosen: Option<Id>) -> bool {
+        match self {
+            Target::Region => chosen == Some(region.id),
+            Target::Income(income) => region.income == *income,
+            Target::Latitude(latitude) => region.latitude == *latitude,
+            Target::RegionFlag(flag) => region.flags.contains(flag),
         }
     }
 }
@@ -166,6 +374,45 @@ pub struct Project {
     pub supporters: Vec<Id>,
     pub opposers: Vec<Id>,
 
+    /// If set, the project's regional effects only apply
+    /// to the targeted regions and its cost scales
+    /// with their share of the population.
+    #[serde(default)]
+    pub target: Option<Target>,
+
+    /// The region chosen by the player,
+    /// for projects targeting a single region.
+    #[serde(default)]
+    pub target_region: Option<Id>,
+
+    /// What has to happen before this project is unlocked.
+    /// Projects with prerequisites start locked.
+    #[serde(default)]
+    pub prerequisites: Vec<Prerequisite>,
+
+    /// What it costs to keep this project going
+    /// each planning cycle once it's online.
+    #[serde(default)]
+    pub upkeep: Option<Upkeep>,
+
+    /// How many planning cycles the project
+    /// has gone unpaid since it stalled.
+    #[serde(default)]
+    pub stalled_cycles: usize,
+
+    /// The chances of setbacks while it's being built.
+    #[serde(default)]
+    pub risk: Risk,
+
+    /// Extra cost from overruns during the current build,
+    /// as a share of the cost. Cleared once the build ends.
+    #[serde(default)]
+    pub overrun: f32,
+
+    /// What it costs, politically, to repeal this policy.
+    #[serde(default)]
+    pub repeal: Repeal,
+
     pub flavor: ProjectFlavor,
     pub notes: String,
 }
@@ -235,6 +482,43 @@ impl Project {
         self.kind == Type::Policy && self.level > 0
     }
 
+    /// How many more years this policy is locked in for.
+    pub fn locked_in_years(&self, year: usize) -> usize {
+        if self.kind == Type::Policy && self.is_online() {
+            (self.completed_at + self.repeal.lock_in).saturating_sub(year)
+        } else {
+            0
+        }
+    }
+
+    /// If this policy can be repealed or downgraded this year.
+    pub fn can_repeal(&self, year: usize) -> bool {
+        self.kind == Type::Policy && self.is_online() && self.locked_in_years(year) == 0
+    }
+
+    /// If the player still has to choose
+    /// which region this project targets.
+    pub fn needs_target_region(&self) -> bool {
+        self.target == Some(Target::Region) && self.target_region.is_none()
+    }
+
+    /// If this project applies to the region.
+    pub fn targets(&self, region: &Region) -> bool {
+        match &self.target {
+            Some(target) => target.matches(region, self.target_region),
+            None => true,
+        }
+    }
+
+    fn changes(&self) -> ProjectChanges {
+        ProjectChanges {
+            id: self.id,
+            target: self.target,
+            target_region: self.target_region,
+            ..Default::default()
+        }
+    }
+
     pub fn years_remaining(&self) -> usize {
         let remaining = 1. - self.progress;
         let progress_per_year = 1. / years_for_points(self.points, self.cost);
@@ -267,7 +551,7 @@ impl Project {
     }
 
     pub fn stop(&mut self) -> (ProjectChanges, bool) {
-        let mut changes = ProjectChanges::default();
+        let mut changes = self.changes();
 
         if self.status == Status::Active || self.status == Status::Finished {
             changes.remove_effects.extend(self.active_effects().clone());
@@ -277,15 +561,16 @@ impl Project {
                 changes.remove_effects.extend(effects.clone());
             }
 
+            let reason = Reason::ProjectStopped(self.id);
             for npc_id in &self.supporters {
                 changes
                     .relationships
-                    .push((*npc_id, -RELATIONSHIP_CHANGE_AMOUNT));
+                    .push((*npc_id, -RELATIONSHIP_CHANGE_AMOUNT, reason.clone()));
             }
             for npc_id in &self.opposers {
                 changes
                     .relationships
-                    .push((*npc_id, RELATIONSHIP_CHANGE_AMOUNT));
+                    .push((*npc_id, RELATIONSHIP_CHANGE_AMOUNT, reason.clone()));
             }
         }
 
@@ -294,43 +579,65 @@ impl Project {
         } else {
             self.status = Status::Inactive;
         }
+        self.overrun = 0.;
 
         let is_policy = self.kind == Type::Policy;
 
         (changes, is_policy)
     }
 
+    pub fn is_stalled(&self) -> bool {
+        self.status == Status::Stalled
+    }
+
+    /// Suspend the project's effects because
+    /// its upkeep couldn't be paid.
+    pub fn stall(&mut self) -> ProjectChanges {
+        let mut changes = self.changes();
+        if self.is_online() {
+            changes.remove_effects.extend(self.active_effects().clone());
+            if let Some(outcome_id) = self.active_outcome {
+                let effects = &self.outcomes[outcome_id].effects;
+                changes.remove_effects.extend(effects.clone());
+            }
+            self.status = Status::Stalled;
+            self.stalled_cycles = 0;
+        }
+        changes
+    }
+
+    /// Bring a stalled project back online
+    /// once its upkeep is paid again.
+    pub fn resume(&mut self) -> ProjectChanges {
+        let mut changes = self.changes();
+        if self.is_stalled() {
+            self.status = if self.ongoing {
+                Status::Active
+            } else {
+                Status::Finished
+            };
+            changes.add_effects.extend(self.active_effects().clone());
+            if let Some(outcome_id) = self.active_outcome {
+                let effects = &self.outcomes[outcome_id].effects;
+                changes.add_effects.extend(effects.clone());
+            }
+        }
+        changes
+    }
+
     pub fn set_points(&mut self, points: usize) {
         self.points = points;
         self.estimate = years_for_points(self.points, self.cost) as usize;
     }
 
-    pub fn update_cost(
-        &mut self,
-        year: usize,
-        income_level: f32,
-        demand: &OutputMap,
-        modifier: f32,
-    ) {
-        let cost = match self.base_cost {
-            Cost::Fixed(c) => c,
-            Cost::Dynamic(m, factor) => {
-                let c = match factor {
-                    // Kind of arbitrarily choose 1980 as the starting point
-                    Factor::Time => m * (year - 1980) as f32,
-                    Factor::Income => m * (1. + income_level),
-                    Factor::Output(output) => m * demand[output],
-                };
-                c.round().max(0.) as usize
-            }
-        };
-        self.cost = (cost as f32 * self.cost_modifier * modifier)
+    pub fn update_cost(&mut self, base_cost: usize, modifier: f32) {
+        self.cost = (base_cost as f32 * self.cost_modifier * (1. + self.overrun) * modifier)
             .round()
             .max(0.) as usize;
     }
 
     pub fn upgrade(&mut self) -> ProjectChanges {
-        let mut changes = ProjectChanges::default();
+        let mut changes = self.changes();
 
         // Upgrade effects replace the previous effects.
         // EXCEPT for locks/unlocks.
@@ -365,8 +672,9 @@ impl Project {
     }
 
     pub fn downgrade(&mut self) -> ProjectChanges {
-        let mut changes = ProjectChanges::default();
+        let mut changes = self.changes();
         changes.remove_effects.extend(self.active_effects().clone());
+        self.overrun = 0.;
 
         let downgraded = if self.level > 0 {
             self.level -= 1;
@@ -396,8 +704,8 @@ impl Project {
         }
     }
 
-    pub fn advance(&mut self, year: usize) -> ProjectChanges {
-        let mut changes = ProjectChanges::default();
+    pub fn advance(&mut self, year: usize, setback: Option<Setback>) -> ProjectChanges {
+        let mut changes = self.changes();
 
         // For gradual projects, we apply
         // interpolated effects.
@@ -408,20 +716,34 @@ impl Project {
             }
         }
 
-        let completed = self.build();
+        changes.setback = setback;
+        let completed = match setback {
+            Some(Setback::Delay) => false,
+            Some(Setback::Failure) => {
+                self.progress = (self.progress - FAILURE_LOSS).max(0.);
+                false
+            }
+            Some(Setback::Overrun) => {
+                self.overrun += OVERRUN_COST;
+                self.build()
+            }
+            None => self.build(),
+        };
         if completed {
             self.completed_at = year;
+            self.overrun = 0.;
             changes.add_effects.extend(self.effects.iter().cloned());
 
+            let reason = Reason::ProjectCompleted(self.id);
             for npc_id in &self.supporters {
                 changes
                     .relationships
-                    .push((*npc_id, RELATIONSHIP_CHANGE_AMOUNT));
+                    .push((*npc_id, RELATIONSHIP_CHANGE_AMOUNT, reason.clone()));
             }
             for npc_id in &self.opposers {
                 changes
                     .relationships
-                    .push((*npc_id, -RELATIONSHIP_CHANGE_AMOUNT));
+                    .push((*npc_id, -RELATIONSHIP_CHANGE_AMOUNT, reason.clone()));
             }
 
             changes.completed = true;
@@ -469,10 +791,15 @@ impl Project {
 
 #[derive(Default, Debug)]
 pub struct ProjectChanges {
+    /// The project (or synergy) these changes are for.
+    pub id: Id,
     pub completed: bool,
+    pub setback: Option<Setback>,
     pub remove_effects: Vec<Effect>,
     pub add_effects: Vec<Effect>,
-    pub relationships: Vec<(Id, f32)>,
+    pub relationships: Vec<(Id, f32, Reason)>,
+    pub target: Option<Target>,
+    pub target_region: Option<Id>,
 }
 
 impl Collection<Project> {
@@ -508,11 +835,16 @@ impl Collection<Project> {
         })
     }
 
-    /// Advance all projects in progress.
-    pub fn step(&mut self, year: usize) -> Vec<(Id, ProjectChanges)> {
+    /// Advance all projects in progress,
+    /// with any setbacks they've run into.
+    pub fn step(
+        &mut self,
+        year: usize,
+        setbacks: &BTreeMap<Id, Setback>,
+    ) -> Vec<(Id, ProjectChanges)> {
         self.in_progress()
             .map(|project| {
-                let updates = project.advance(year);
+                let updates = project.advance(year, setbacks.get(&project.id).copied());
                 (project.id, updates)
             })
             .collect()
@@ -522,7 +854,7 @@ impl Collection<Project> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::events::Likelihood;
+    use crate::events::{Flag, Likelihood};
 
     #[test]
     fn test_build_project() {
@@ -637,4 +969,349 @@ mod test {
         // let (_outcome, i) = outcome.unwrap();
         // assert_eq!(i, 0);
     }
+
+    #[test]
+    fn test_targeted_project() {
+        use crate::{
+            events::{LocalVariable, PlayerVariable},
+            state::{Changes, State},
+        };
+
+        let mut state = State::default();
+        let region_id = state.world.regions.by_idx(0).id;
+        let project = Project {
+            base_cost: Cost::Fixed(100),
+            cost_modifier: 1.,
+            target: Some(Target::Region),
+            ..Project::new()
+        };
+        let id = project.id;
+        state.world.projects.push(project);
+
+        state.set_project_target(&id, &region_id);
+        let cost = state.world.projects[&id].cost;
+        assert!(cost > 0 && cost < 100);
+
+        let outlooks: Vec<f32> = state.world.regions.iter().map(|r| r.outlook).collect();
+        let political_capital = state.political_capital;
+        let effects = vec![
+            Effect::LocalVariable(LocalVariable::Outlook, 1.),
+            Effect::PlayerVariable(PlayerVariable::PoliticalCapital, 10.),
+        ];
+        state.world.projects[&id].effects = effects.clone();
+        state.world.projects[&id].status = Status::Finished;
+        let changes = ProjectChanges {
+            add_effects: effects,
+            ..state.world.projects[&id].changes()
+        };
+        changes.apply(&mut state);
+
+        // Regional effects only apply to the targeted region,
+        // other effects apply once.
+        for (region, outlook) in state.world.regions.iter().zip(&outlooks) {
+            if region.id == region_id {
+                assert_eq!(region.outlook, outlook + 1.);
+            } else {
+                assert_eq!(region.outlook, *outlook);
+            }
+        }
+        assert_eq!(state.political_capital, political_capital + 10);
+
+        // Effects are removed from the regions they were
+        // applied to, even if the target has since changed.
+        let other_id = state.world.regions.by_idx(1).id;
+        state.world.projects[&id].target_region = Some(other_id);
+        state.stop_project(&id);
+        for (region, outlook) in state.world.regions.iter().zip(&outlooks) {
+            assert_eq!(region.outlook, *outlook);
+        }
+        assert!(!state.applied_regions.contains_key(&id));
+    }
+
+    #[test]
+    fn test_project_upkeep() {
+        use crate::{
+            events::PlayerVariable,
+            state::{MAX_STALLED_CYCLES, State},
+        };
+
+        let mut state = State::default();
+        let project = Project {
+            status: Status::Active,
+            ongoing: true,
+            progress: 1.,
+            upkeep: Some(Upkeep::PoliticalCapital(10)),
+            effects: vec![Effect::PlayerVariable(PlayerVariable::ResearchPoints, 1.)],
+            ..Project::new()
+        };
+        let id = project.id;
+        state.world.projects.push(project);
+        state.research_points = 1;
+
+        state.political_capital = 15;
+        state.pay_upkeep();
+        assert_eq!(state.political_capital, 5);
+        assert!(state.world.projects[&id].is_active());
+        assert_eq!(state.upkeep_due(), (10, 0));
+
+        // Can't pay, so the project's effects are suspended.
+        state.pay_upkeep();
+        assert_eq!(state.political_capital, 5);
+        assert!(state.world.projects[&id].is_stalled());
+        assert_eq!(state.research_points, 0);
+
+        // Paying again brings it back.
+        state.political_capital = 10;
+        state.pay_upkeep();
+        assert_eq!(state.political_capital, 0);
+        assert!(state.world.projects[&id].is_active());
+        assert_eq!(state.research_points, 1);
+
+        // Left unpaid for too long it's halted.
+        for _ in 0..=MAX_STALLED_CYCLES + 1 {
+            state.pay_upkeep();
+        }
+        assert_eq!(state.world.projects[&id].status, Status::Halted);
+        assert_eq!(state.upkeep_due(), (0, 0));
+    }
+
+    #[test]
+    fn test_stalled_project_regions() {
+        use crate::{
+            events::LocalVariable,
+            state::{Changes, State},
+        };
+
+        let mut state = State::default();
+        let region_id = state.world.regions.by_idx(0).id;
+        let other_id = state.world.regions.by_idx(1).id;
+        let effects = vec![Effect::LocalVariable(LocalVariable::Outlook, 1.)];
+        let project = Project {
+            status: Status::Finished,
+            upkeep: Some(Upkeep::PoliticalCapital(10)),
+            target: Some(Target::Region),
+            target_region: Some(region_id),
+            effects: effects.clone(),
+            ..Project::new()
+        };
+        let id = project.id;
+        state.world.projects.push(project);
+        let changes = ProjectChanges {
+            add_effects: effects,
+            ..state.world.projects[&id].changes()
+        };
+        changes.apply(&mut state);
+
+        let outlook = |state: &State, id: &Id| state.world.regions[id].outlook;
+        let outlooks = (outlook(&state, &region_id), outlook(&state, &other_id));
+
+        // Stalling removes the effects from the regions
+        // they were applied to, even if the target changed.
+        state.world.projects[&id].target_region = Some(other_id);
+        state.political_capital = 0;
+        state.pay_upkeep();
+        assert!(state.world.projects[&id].is_stalled());
+        assert_eq!(outlook(&state, &region_id), outlooks.0 - 1.);
+        assert_eq!(outlook(&state, &other_id), outlooks.1);
+        assert!(!state.applied_regions.contains_key(&id));
+
+        // Resuming applies them to the current target.
+        state.political_capital = 10;
+        state.pay_upkeep();
+        assert!(state.world.projects[&id].is_finished());
+        assert_eq!(outlook(&state, &region_id), outlooks.0 - 1.);
+        assert_eq!(outlook(&state, &other_id), outlooks.1 + 1.);
+
+        // And stalling again removes them from there.
+        state.world.projects[&id].target_region = Some(region_id);
+        state.pay_upkeep();
+        assert_eq!(outlook(&state, &region_id), outlooks.0 - 1.);
+        assert_eq!(outlook(&state, &other_id), outlooks.1);
+    }
+
+    #[test]
+    fn test_dynamic_costs() {
+        let mut state = State::default();
+        state.world.temperature = 1.5;
+        state.world.sea_level_rise = 0.5;
+        let project = state.world.projects.first().clone();
+        let value = |factor: Factor| factor.value(&state, &project);
+
+        assert_eq!(value(Factor::Temperature), 1.5);
+        assert_eq!(value(Factor::Constant(2.)), 2.);
+        assert_eq!(
+            value(Factor::Sum(vec![
+                Factor::Constant(1.),
+                Factor::SeaLevelRise
+            ])),
+            1.5
+        );
+        assert_eq!(
+            value(Factor::Product(vec![
+                Factor::Constant(4.),
+                Factor::Temperature
+            ])),
+            6.
+        );
+        assert_eq!(value(Factor::Inverse(Box::new(Factor::Constant(4.)))), 0.25);
+        assert_eq!(value(Factor::Inverse(Box::new(Factor::Constant(0.)))), 10.);
+        assert_eq!(value(Factor::Inverse(Box::new(Factor::Constant(-1.)))), 10.);
+        assert_eq!(
+            value(Factor::Regions(Income::Low)),
+            state.world.regions.len() as f32
+        );
+
+        // Geoengineering getting cheaper as it warms.
+        let cost = Cost::Dynamic(
+            16.,
+            Factor::Inverse(Box::new(Factor::Sum(vec![
+                Factor::Constant(0.5),
+                Factor::Temperature,
+            ]))),
+        );
+        assert_eq!(cost.base_amount(&state, &project), 8);
+        state.world.temperature = 3.5;
+        assert_eq!(cost.base_amount(&state, &project), 4);
+
+        // Other online projects in the group.
+        let others: Vec<Id> = state
+            .world
+            .projects
+            .iter()
+            .filter(|p| p.id != project.id && p.group == project.group)
+            .map(|p| p.id)
+            .take(2)
+            .collect();
+        assert_eq!(Factor::GroupProjects.value(&state, &project), 0.);
+        for id in &others {
+            state.world.projects[id].status = Status::Active;
+        }
+        assert_eq!(
+            Factor::GroupProjects.value(&state, &project),
+            others.len() as f32
+        );
+    }
+
+    #[test]
+    fn test_construction_setbacks() {
+        let mut state = State::default();
+        let id = state
+            .world
+            .projects
+            .iter()
+            .find(|p| p.kind == Type::Initiative && p.opposers.is_empty() && p.target.is_none())
+            .unwrap()
+            .id;
+        let project = &mut state.world.projects[&id];
+        project.status = Status::Building;
+        project.set_points(1);
+        project.cost = 10;
+        project.advance(2022, None);
+        let progress = project.progress;
+        assert!(progress > 0.);
+
+        let changes = project.advance(2023, Some(Setback::Delay));
+        assert_eq!(changes.setback, Some(Setback::Delay));
+        assert_eq!(project.progress, progress);
+
+        project.advance(2024, Some(Setback::Failure));
+        assert_eq!(project.progress, 0.);
+
+        let cost_modifier = project.cost_modifier;
+        project.advance(2025, Some(Setback::Overrun));
+        assert_eq!(project.cost_modifier, cost_modifier);
+        assert_eq!(project.overrun, OVERRUN_COST);
+        assert!(project.progress > 0.);
+        project.update_cost(10, 1.);
+        assert_eq!(project.cost, 11);
+
+        // Overruns only last for the build.
+        project.stop();
+        assert_eq!(project.overrun, 0.);
+        project.update_cost(10, 1.);
+        assert_eq!(project.cost, 10);
+        project.status = Status::Building;
+
+        // Risks get worse with labor sabotage.
+        let risk = Risk {
+            delay: 0.1,
+            overrun: 0.1,
+            failure: 0.1,
+        };
+        project.risk = risk;
+        let (years, likely) = state.construction_estimate(&state.world.projects[&id]);
+        assert!(likely > years);
+        assert_eq!(state.construction_risk(&state.world.projects[&id]), risk);
+        state.flags.push(Flag::LaborSabotage);
+        assert!(state.construction_risk(&state.world.projects[&id]).any() > risk.any());
+    }
+
+    #[test]
+    fn test_policy_repeal() {
+        use crate::{
+            events::WorldVariable,
+            ledger::{Currency, Source},
+        };
+
+        let mut state = State::default();
+        let npcs: Vec<Id> = state.npcs.iter().take(2).map(|npc| npc.id).collect();
+        let year = state.world.year;
+        let project = Project {
+            kind: Type::Policy,
+            status: Status::Active,
+            completed_at: year,
+            supporters: vec![npcs[0]],
+            opposers: vec![npcs[1]],
+            effects: vec![Effect::WorldVariable(WorldVariable::Emissions, 1.)],
+            upgrades: vec![Upgrade::default()],
+            level: 1,
+            repeal: Repeal {
+                cost: 10,
+                supporter_backlash: 1.,
+                opposer_approval: 0.5,
+                outlook: -2.,
+                lock_in: 5,
+            },
+            ..Project::new()
+        };
+        let id = project.id;
+        state.world.projects.push(project);
+        state.political_capital = 5;
+
+        // Locked in for now.
+        assert_eq!(state.world.projects[&id].locked_in_years(year + 2), 3);
+        assert!(!state.repeal_policy(&id));
+        assert_eq!(state.world.projects[&id].level, 1);
+
+        // Downgrades don't carry the fallout...
+        state.world.year = year + 5;
+        assert!(state.repeal_policy(&id));
+        assert_eq!(state.world.projects[&id].level, 0);
+        assert_eq!(state.political_capital, 5);
+
+        // ...but a full repeal does, once it can be afforded.
+        assert!(!state.repeal_policy(&id));
+        assert!(state.world.projects[&id].is_active());
+
+        state.political_capital = 15;
+        let supporter = state.npcs[&npcs[0]].relationship;
+        let opposer = state.npcs[&npcs[1]].relationship;
+        let outlook = state.world.base_outlook;
+        assert!(state.repeal_policy(&id));
+        assert!(!state.world.projects[&id].is_online());
+        assert_eq!(state.political_capital, 5);
+        assert_eq!(
+            state.npcs[&npcs[0]].relationship,
+            supporter - RELATIONSHIP_CHANGE_AMOUNT - 1.
+        );
+        assert_eq!(
+            state.npcs[&npcs[1]].relationship,
+            opposer + RELATIONSHIP_CHANGE_AMOUNT + 0.5
+        );
+        assert_eq!(state.world.base_outlook, outlook - 2.);
+        assert_eq!(
+            state.ledger.breakdown(Currency::PoliticalCapital)[&Source::Repeal].expenses,
+            10
+        );
+    }
 }
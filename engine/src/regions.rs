@@ -1,12 +1,67 @@
-use std::fmt::Display;
+use std::{collections::BTreeMap, fmt::Display};
 
-use crate::{HasId, Id, events::RegionFlag, flavor::RegionFlavor, kinds::*, outputs};
+use crate::{
+    Collection,
+    HasId,
+    Id,
+    disasters::Hazard,
+    events::RegionFlag,
+    flavor::RegionFlavor,
+    kinds::*,
+    outputs,
+    production::Process,
+};
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter, EnumString, IntoStaticStr};
 
 // 40 years per level
 const DEVELOP_SPEED: f32 = 1. / 40.;
 
+// Seasonal precipitation (cm) below which
+// droughts start to lower habitability.
+const DROUGHT_PRECIP: f32 = 2.;
+
+// Seasonal precipitation (cm) above which
+// floods start to lower habitability.
+const FLOOD_PRECIP: f32 = 350.;
+
+// Yearly variability of local temperature (°C) and
+// precipitation (fraction), and how much it widens
+// per degree of global warming.
+const TEMP_VARIABILITY: f32 = 0.5;
+const PRECIP_VARIABILITY: f32 = 0.05;
+const VARIABILITY_WARMING: f32 = 0.1;
+
+// Local change from the pre-industrial climate at which
+// an extreme is expected every year: warming (°C) of the
+// hottest season for heatwaves, drying (cm) of the driest
+// season for droughts, and wetting (cm) of the wettest
+// season for floods.
+const HEATWAVE_WARMING: f32 = 6.;
+const DROUGHT_DRYING: f32 = 20.;
+const FLOOD_WETTING: f32 = 100.;
+
+// Share of disaster damage that's recovered every year.
+const DISASTER_RECOVERY: f32 = 0.1;
+
+// Annual birth and death rates before (0) and
+// after (1) the demographic transition.
+// Calibrated to the cubic growth curves
+// the game previously used, see `test_pop_calibration`.
+const BIRTH_RATE: (f32, f32) = (0.0565, 0.0122);
+const DEATH_RATE: (f32, f32) = (0.02, 0.0142);
+
+// Base annual progress through the demographic transition.
+const TRANSITION_SPEED: f32 = 0.0054;
+
+// How much each income level speeds up the transition.
+const TRANSITION_INCOME: f32 = 0.25;
+
+// Habitability below which mortality rises,
+// and by how much per point below it.
+const LOW_HABITABILITY: f32 = 5.;
+const HABITABILITY_MORTALITY: f32 = 0.002;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct Region {
     pub id: Id,
@@ -16,9 +71,26 @@ pub struct Region {
     pub population: f32,
     pub seceded: bool,
 
+    /// A seceded region produces for itself
+    /// with the process mix it had when it left.
+    #[serde(default)]
+    pub process_mix: BTreeMap<Id, usize>,
+
+    /// Progress towards negotiating a seceded
+    /// region's return, from 0.0 to 1.0.
+    #[serde(default)]
+    pub reunification: f32,
+
     pub income: Income,
     pub development: f32,
 
+    /// Progress through the demographic transition, from
+    /// high birth and death rates (0.0) to low ones (1.0).
+    /// Saves from before it was tracked don't have it,
+    /// see `Region::transition`.
+    #[serde(default)]
+    pub transition: Option<f32>,
+
     pub flags: Vec<RegionFlag>,
 
     /// How hopeful are people in the region about the future?
@@ -26,10 +98,26 @@ pub struct Region {
 
     /// Base habitability encapsulates
     /// other factors that influence habitability.
-    /// E.g. negative events such as hurricanes should subtract
-    /// from this value
+    /// Lasting changes from events modify this value;
+    /// temporary damage from disasters is tracked
+    /// separately in `disaster_damage`.
     pub base_habitability: f32,
 
+    /// Share of the population (0 to 1) living along the coast,
+    /// and so exposed to sea level rise.
+    #[serde(default)]
+    pub coastal_population: f32,
+
+    /// Habitability lost to recent disasters,
+    /// which the region gradually recovers from.
+    #[serde(default)]
+    pub disaster_damage: f32,
+
+    /// How well the region is protected from each kind of disaster,
+    /// e.g. by sea walls or early warning systems.
+    #[serde(default)]
+    pub resilience: BTreeMap<Hazard, f32>,
+
     /// Local temperature and precipitation
     pub temp_lo: f32,
     pub temp_hi: f32,
@@ -37,6 +125,23 @@ pub struct Region {
     pub precip_hi: f32,
     pub latitude: Latitude,
 
+    /// This year's departure from the expected local
+    /// temperature (°C) and precipitation (fraction).
+    #[serde(default)]
+    pub temp_anomaly: f32,
+    #[serde(default)]
+    pub precip_anomaly: f32,
+
+    /// Likelihood of extreme weather this year,
+    /// given the local climate.
+    #[serde(default)]
+    pub climate_risks: ClimateRisks,
+
+    /// Lasting changes to those likelihoods,
+    /// e.g. from adaptation projects.
+    #[serde(default)]
+    pub risk_modifiers: ClimateRisks,
+
     pub flavor: RegionFlavor,
     pub pattern_idxs: Vec<usize>,
 }
@@ -62,13 +167,110 @@ impl Region {
         (start, end)
     }
 
-    pub fn habitability(&self) -> f32 {
-        // Factors:
-        // - [X] regional temp
-        // - [ ] precip TODO
-        // - [ ] sea_level_rise TODO
-        // - [X] number of negative events
-        (self.base_habitability - (f32::max(0., self.temp_hi - 35.).powf(2.) * 10.)).max(0.)
+    pub fn habitability(&self, sea_level_rise: f32) -> f32 {
+        (self.base_habitability
+            - self.heat_penalty()
+            - self.drought_penalty()
+            - self.flood_penalty()
+            - self.coastal_penalty(sea_level_rise)
+            - self.disaster_damage)
+            .max(0.)
+    }
+
+    pub fn heat_penalty(&self) -> f32 {
+        f32::max(0., self.temp_hi - 35.).powf(2.) * 10.
+    }
+
+    pub fn drought_penalty(&self) -> f32 {
+        f32::max(0., DROUGHT_PRECIP - self.precip_lo) * 0.5
+    }
+
+    pub fn flood_penalty(&self) -> f32 {
+        f32::max(0., self.precip_hi - FLOOD_PRECIP) / 50.
+    }
+
+    /// Coastal populations are displaced as the sea rises.
+    pub fn coastal_penalty(&self, sea_level_rise: f32) -> f32 {
+        self.coastal_population * sea_level_rise.max(0.) * 5.
+    }
+
+    /// Set this year's local climate from the scaled pattern
+    /// (`[temp_lo, temp_hi, precip_lo, precip_hi]`), with some
+    /// yearly variability. Extremes are judged against the
+    /// pre-industrial `baseline` pattern. `noise` holds
+    /// standard normal draws for temperature and precipitation.
+    pub fn update_climate(
+        &mut self,
+        pattern: &[f32; 4],
+        baseline: &[f32; 4],
+        tgav: f32,
+        noise: (f32, f32),
+    ) {
+        let spread = 1. + VARIABILITY_WARMING * tgav.max(0.);
+        self.temp_anomaly = noise.0 * TEMP_VARIABILITY * spread;
+        self.precip_anomaly = noise.1 * PRECIP_VARIABILITY * spread;
+        self.temp_lo = pattern[0] + self.temp_anomaly;
+        self.temp_hi = pattern[1] + self.temp_anomaly;
+        self.precip_lo = pattern[2] * (1. + self.precip_anomaly);
+        self.precip_hi = pattern[3] * (1. + self.precip_anomaly);
+
+        let warming = (self.temp_hi - baseline[1]) / HEATWAVE_WARMING;
+        let drying = (baseline[2] - self.precip_lo) / DROUGHT_DRYING;
+        let wetting = (self.precip_hi - baseline[3]) / FLOOD_WETTING;
+
+        // Heat dries out soils and a warmer atmosphere
+        // holds more moisture, so warming also makes
+        // droughts and floods more likely.
+        let risk = |base: f32, modifier: f32| (base + modifier).clamp(0., 1.);
+        self.climate_risks = ClimateRisks {
+            heatwave: risk(warming, self.risk_modifiers.heatwave),
+            drought: risk(drying + warming * 0.5, self.risk_modifiers.drought),
+            flood: risk(wetting + warming * 0.25, self.risk_modifiers.flood),
+        };
+    }
+
+    pub fn damage(&mut self, amount: f32) {
+        self.disaster_damage += amount.max(0.);
+    }
+
+    /// Recover from disasters. Wealthier regions
+    /// have more resources to rebuild with.
+    pub fn recover(&mut self) {
+        let rate = DISASTER_RECOVERY * (1. + 0.5 * self.income.level() as f32);
+        self.disaster_damage *= 1. - rate.min(1.);
+        if self.disaster_damage < 0.01 {
+            self.disaster_damage = 0.;
+        }
+    }
+
+    pub fn secede(&mut self, processes: &Collection<Process>) {
+        self.seceded = true;
+        self.reunification = 0.;
+        self.process_mix = processes
+            .iter()
+            .map(|process| (process.id, process.mix_share))
+            .collect();
+    }
+
+    pub fn rejoin(&mut self) {
+        self.seceded = false;
+        self.reunification = 0.;
+        self.process_mix.clear();
+    }
+
+    /// Advance reunification talks,
+    /// returning true if the region rejoined.
+    pub fn negotiate_reunification(&mut self, progress: f32) -> bool {
+        if !self.seceded {
+            return false;
+        }
+        self.reunification = (self.reunification + progress).max(0.);
+        if self.reunification >= 1. {
+            self.rejoin();
+            true
+        } else {
+            false
+        }
     }
 
     pub fn set_income_level(&mut self, level: usize) {
@@ -99,24 +301,43 @@ impl Region {
         demand_levels
     }
 
-    pub fn update_pop(&mut self, year: f32, modifier: f32, income_pop_coefs: &[[f32; 4]; 4]) {
-        let coefs = income_pop_coefs[self.income.level()];
-        let change = coefs[0]
-            + (coefs[1] * year)
-            + (coefs[2] * year.powf(2.0))
-            + (coefs[3] * year.powf(3.0));
+    /// Progress through the demographic transition, falling
+    /// back to what's typical for the region's income level.
+    pub fn transition(&self) -> f32 {
+        self.transition
+            .unwrap_or_else(|| self.income.typical_transition())
+    }
+
+    /// Births fall as the region moves through
+    /// the demographic transition.
+    pub fn birth_rate(&self) -> f32 {
+        let (pre, post) = BIRTH_RATE;
+        pre - (pre - post) * self.transition()
+    }
+
+    /// Deaths fall earlier in the transition than births do,
+    /// and rise again when the region becomes hard to live in.
+    pub fn death_rate(&self, sea_level_rise: f32) -> f32 {
+        let (pre, post) = DEATH_RATE;
+        let hardship = (LOW_HABITABILITY - self.habitability(sea_level_rise)).max(0.);
+        post + (pre - post) * (1. - self.transition()).powi(2) + hardship * HABITABILITY_MORTALITY
+    }
+
+    /// Grow (or shrink) the population, then advance the
+    /// demographic transition. Wealthier regions transition faster.
+    pub fn update_pop(&mut self, sea_level_rise: f32, modifier: f32, transition_modifier: f32) {
+        let change = self.birth_rate() - self.death_rate(sea_level_rise);
         self.population *= 1. + (change * modifier);
+
+        let speed = TRANSITION_SPEED
+            * (1. + TRANSITION_INCOME * self.adjusted_income())
+            * (1. + transition_modifier).max(0.);
+        self.transition = Some((self.transition() + speed).min(1.));
     }
 
     // Outlook slowly rebounds over time
-    pub fn update_outlook(&mut self, wretched_ally: bool, consumerist_ally: bool) {
-        let buffed = match self.income {
-            Income::Low => wretched_ally,
-            Income::LowerMiddle => wretched_ally,
-            Income::UpperMiddle => consumerist_ally,
-            Income::High => consumerist_ally,
-        };
-        self.outlook += if buffed { 0.3 } else { 0.1 };
+    pub fn update_outlook(&mut self, recovery: f32) {
+        self.outlook += recovery;
         self.outlook = f32::min(10., self.outlook);
     }
 
@@ -178,6 +399,15 @@ impl Region {
     }
 }
 
+/// Yearly likelihood (0 to 1) of
+/// extreme weather in a region.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ClimateRisks {
+    pub heatwave: f32,
+    pub drought: f32,
+    pub flood: f32,
+}
+
 #[derive(
     Default,
     PartialEq,
@@ -230,6 +460,17 @@ impl Income {
         }
     }
 
+    /// How far through the demographic transition
+    /// regions at this income level usually are.
+    pub fn typical_transition(&self) -> f32 {
+        match self {
+            Income::Low => 0.4,
+            Income::LowerMiddle => 0.55,
+            Income::UpperMiddle => 0.75,
+            Income::High => 0.8,
+        }
+    }
+
     pub fn level(&self) -> usize {
         match self {
             Income::Low => 0,
@@ -270,3 +511,213 @@ pub enum Latitude {
     Temperate,
     Frigid,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn gen_region() -> Region {
+        Region {
+            base_habitability: 10.,
+            temp_hi: 30.,
+            precip_lo: 10.,
+            precip_hi: 200.,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_pop_calibration() {
+        // The cubic annual growth curves, by income level,
+        // that the demographic model replaced.
+        const INCOME_POP_COEFS: [[f32; 4]; 4] = [
+            [-137.09105, 0.20175901, -9.881497e-05, 1.610786e-08],
+            [-31.64509, 0.04905328, -2.514448e-05, 4.267315e-09],
+            [-73.97073, 0.11030435, -5.4715376e-05, 9.029389e-09],
+            [193.77744, -0.2777678, 0.00013271414, -2.1135532e-08],
+        ];
+        let cubic_pop = |region: &Region, start: usize, end: usize| {
+            let coefs = INCOME_POP_COEFS[region.income.level()];
+            let mut population = region.population;
+            for year in start..end {
+                let year = year as f32;
+                let change = coefs[0]
+                    + (coefs[1] * year)
+                    + (coefs[2] * year.powf(2.0))
+                    + (coefs[3] * year.powf(3.0));
+                population *= 1. + change;
+            }
+            population
+        };
+
+        // Each default region should end up within 5%
+        // of the old trajectory by 2100, holding income fixed.
+        let world = crate::World::default();
+        for region in world.regions.iter() {
+            let expected = cubic_pop(region, world.year, 2100);
+            let mut region = region.clone();
+            for _ in world.year..2100 {
+                region.update_pop(0., 1., 0.);
+            }
+            let diff = (region.population - expected).abs() / expected;
+            assert!(diff < 0.05, "{}: {} vs {}", region.name, region.population, expected);
+        }
+
+        // And the population should level off
+        // rather than run away after that.
+        let mut region = world.regions.by_idx(0).clone();
+        for _ in 2100..2300 {
+            region.update_pop(0., 1., 0.);
+        }
+        let rate = region.birth_rate() - region.death_rate(0.);
+        assert!(rate.abs() < 0.005);
+    }
+
+    #[test]
+    fn test_demographic_transition() {
+        let mut region = Region {
+            population: 1000.,
+            transition: Some(0.5),
+            ..gen_region()
+        };
+        let start = region.birth_rate() - region.death_rate(0.);
+
+        // Policies can speed up the transition.
+        let mut faster = region.clone();
+        region.update_pop(0., 1., 0.);
+        faster.update_pop(0., 1., 1.);
+        assert!(faster.transition() > region.transition());
+        assert!(region.birth_rate() - region.death_rate(0.) < start);
+
+        // Uninhabitable regions lose people.
+        region.base_habitability = 0.;
+        assert!(region.birth_rate() - region.death_rate(0.) < start - 0.005);
+    }
+
+    #[test]
+    fn test_transition_in_old_saves() {
+        // Regions saved before the transition was tracked
+        // pick up where their income level usually is.
+        let world = crate::World::default();
+        for region in world.regions.iter() {
+            let mut json = serde_json::to_value(region).unwrap();
+            json.as_object_mut().unwrap().remove("transition");
+            let loaded: Region = serde_json::from_value(json).unwrap();
+            assert_eq!(loaded.transition, None);
+            assert!((loaded.transition() - region.transition()).abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn test_habitability_curves() {
+        let mut region = gen_region();
+        assert_eq!(region.habitability(0.), 10.);
+
+        // Heat
+        region.temp_hi = 37.;
+        assert_eq!(region.heat_penalty(), 40.);
+        assert_eq!(region.habitability(0.), 0.);
+        region.temp_hi = 30.;
+
+        // Drought
+        region.precip_lo = 2.;
+        assert_eq!(region.drought_penalty(), 0.);
+        region.precip_lo = -2.;
+        assert_eq!(region.drought_penalty(), 2.);
+        assert_eq!(region.habitability(0.), 8.);
+        region.precip_lo = 10.;
+
+        // Flood
+        region.precip_hi = 450.;
+        assert_eq!(region.flood_penalty(), 2.);
+        assert_eq!(region.habitability(0.), 8.);
+        region.precip_hi = 200.;
+
+        // Sea level rise only affects coastal populations
+        assert_eq!(region.habitability(1.), 10.);
+        region.coastal_population = 0.2;
+        assert_eq!(region.coastal_penalty(1.), 1.);
+        assert_eq!(region.coastal_penalty(-1.), 0.);
+        assert_eq!(region.habitability(1.), 9.);
+    }
+
+    #[test]
+    fn test_disaster_recovery() {
+        let mut low = gen_region();
+        let mut high = Region {
+            income: Income::High,
+            ..gen_region()
+        };
+        low.damage(4.);
+        high.damage(4.);
+        assert_eq!(low.habitability(0.), 6.);
+
+        low.recover();
+        high.recover();
+        assert!(low.habitability(0.) > 6.);
+        assert!(high.habitability(0.) > low.habitability(0.));
+
+        for _ in 0..100 {
+            low.recover();
+        }
+        assert_eq!(low.disaster_damage, 0.);
+        assert_eq!(low.habitability(0.), 10.);
+    }
+
+    #[test]
+    fn test_climate_variability() {
+        let baseline = [10., 30., 10., 200.];
+        let mut region = gen_region();
+
+        // Without variability or change there are no extremes.
+        region.update_climate(&baseline, &baseline, 0., (0., 0.));
+        assert_eq!(region.temp_hi, 30.);
+        assert_eq!(region.climate_risks, ClimateRisks::default());
+
+        // Warming makes every extreme more likely.
+        let warmer = [12., 33., 5., 250.];
+        region.update_climate(&warmer, &baseline, 2., (0., 0.));
+        let risks = region.climate_risks;
+        assert!(risks.heatwave > 0.);
+        assert!(risks.drought > 0.);
+        assert!(risks.flood > 0.);
+
+        // As does an unusually hot and wet year.
+        region.update_climate(&warmer, &baseline, 2., (1., 1.));
+        assert!(region.temp_anomaly > 0.);
+        assert!(region.climate_risks.heatwave > risks.heatwave);
+        assert!(region.climate_risks.flood > risks.flood);
+
+        // Adaptation lowers the risk, but never below zero.
+        region.risk_modifiers.heatwave = -1.;
+        region.update_climate(&warmer, &baseline, 2., (0., 0.));
+        assert_eq!(region.climate_risks.heatwave, 0.);
+    }
+
+    #[test]
+    fn test_secession() {
+        let mut processes: Collection<Process> = Collection::default();
+        let mut process = Process::new();
+        process.mix_share = 10;
+        let process_id = process.id;
+        processes.push(process);
+
+        let mut region = gen_region();
+        assert!(!region.negotiate_reunification(1.));
+
+        region.secede(&processes);
+        assert!(region.seceded);
+        assert_eq!(region.process_mix.get(&process_id), Some(&10));
+
+        // The region keeps its mix even if the player's changes.
+        processes[&process_id].mix_share = 0;
+        assert_eq!(region.process_mix.get(&process_id), Some(&10));
+
+        assert!(!region.negotiate_reunification(0.5));
+        assert_eq!(region.reunification, 0.5);
+        assert!(region.negotiate_reunification(0.5));
+        assert!(!region.seceded);
+        assert!(region.process_mix.is_empty());
+        assert_eq!(region.reunification, 0.);
+    }
+}
@@ -2,13 +2,28 @@ use std::collections::BTreeMap;
 
 use crate::{
     Collection, Id,
-    events::{Condition, Effect, Event, EventPool, Flag, Phase, Request},
+    agendas::{AgendaRequest, Agendas, ClosedRequests, Demand},
+    carbon::CarbonBudget,
+    construction::{Risk, Setback},
+    disasters::DisasterImpact,
+    elections::{CAMPAIGN_COST, ElectionResult, Elections},
+    events::{
+        Condition, Effect, Event, EventPool, Flag, IconEvent, Phase, Request, StorylineProgress,
+        WorldVariable,
+    },
     kinds::*,
-    npcs::NPC,
+    land::Land,
+    ledger::{Currency, Ledger, Source},
+    migration::significant_emigration,
+    npcs::{NPC, NPCRelation, RELATIONSHIP_CHANGE_AMOUNT, Reason},
+    parliament::{VoteResult, simulate_vote},
     outputs,
     production::{ProcessChanges, calculate_required, produce},
-    projects::{Group, Outcome, Project, ProjectChanges, Status, Type as ProjectType},
+    projects::{
+        Group, Outcome, Project, ProjectChanges, Status, Target, Type as ProjectType, Upkeep,
+    },
     resources,
+    tipping::{TippingPoint, TippingProgress},
     world::World,
 };
 use serde::{Deserialize, Serialize};
@@ -16,6 +31,26 @@ use serde::{Deserialize, Serialize};
 const LIFESPAN: usize = 60;
 const PRODUCTION_SHORTAGE_PENALTY: f32 = 60.;
 
+/// Minimum share of a project's cost
+/// paid when it targets only a few people.
+const MIN_TARGET_COST_SCALE: f32 = 0.1;
+
+/// Share of a region's population that disasters have
+/// to kill or displace in a year for it to be reported.
+const SIGNIFICANT_DISASTER_DEATHS: f32 = 0.001;
+
+/// How much more likely construction setbacks are
+/// while labor is sabotaging projects.
+const LABOR_SABOTAGE_RISK: f32 = 0.5;
+
+/// How much more likely construction setbacks are
+/// for each faction opposing the project.
+const OPPOSITION_RISK: f32 = 0.25;
+
+/// How many more planning cycles a stalled project
+/// can go unpaid before it's halted for good.
+pub(crate) const MAX_STALLED_CYCLES: usize = 1;
+
 /// Have to all be below these values to win
 const WIN_EMISSIONS: f32 = 0.0;
 const WIN_EXTINCTION: f32 = 20.0;
@@ -32,6 +67,12 @@ pub struct State {
 
     pub political_capital: isize,
     pub research_points: isize,
+
+    /// Where political capital and research points
+    /// came from and went to this planning cycle.
+    #[serde(default)]
+    pub ledger: Ledger,
+
     pub npcs: Collection<NPC>,
 
     // Requests: (
@@ -47,6 +88,26 @@ pub struct State {
     // need to have rolled outcomes
     pub policy_queue: Vec<Id>,
 
+    /// Factions won over by bargaining,
+    /// keyed by project id.
+    #[serde(default)]
+    pub vote_bargains: BTreeMap<Id, Vec<Id>>,
+
+    /// Regions currently losing a significant
+    /// share of their population to migration.
+    #[serde(default)]
+    pub emigrating: Vec<Id>,
+
+    /// What disasters have done to each region this year,
+    /// reported and cleared when the year ends.
+    #[serde(default)]
+    pub disaster_impacts: BTreeMap<Id, DisasterImpact>,
+
+    /// Projects that have been unlocked by meeting their
+    /// prerequisites, so they're only unlocked the once.
+    #[serde(default)]
+    pub prerequisites_met: Vec<Id>,
+
     pub produced: Production,
     pub resources: Resources,
     pub feedstocks: Feedstocks,
@@ -58,15 +119,50 @@ pub struct State {
     /// scaling in `Process::extinction_rate`.
     pub byproducts: Byproducts,
 
-    pub protected_land: f32,
+    /// How the world's land is allocated between uses.
+    #[serde(default)]
+    pub land: Land,
 
     pub shortages_outlook: f32,
     pub emissions: Emissions,
     pub last_outlook: f32,
 
+    /// Emissions accumulated since the start of the game.
+    #[serde(default)]
+    pub carbon_budget: CarbonBudget,
+
     pub events: Vec<Event>,
 
     pub event_pool: EventPool,
+
+    /// Progress through the world's storylines,
+    /// keyed by storyline id.
+    #[serde(default)]
+    pub storylines: BTreeMap<Id, StorylineProgress>,
+
+    /// Progress towards the world's tipping points,
+    /// keyed by tipping point id.
+    #[serde(default)]
+    pub tipping_points: BTreeMap<Id, TippingProgress>,
+
+    /// The effects applied by active synergies,
+    /// keyed by synergy id.
+    #[serde(default)]
+    pub synergies: BTreeMap<Id, Vec<Effect>>,
+
+    /// The regions each targeted project's or synergy's
+    /// effects were applied to, keyed by project or synergy id,
+    /// so they're removed from the same regions.
+    #[serde(default)]
+    pub applied_regions: BTreeMap<Id, Vec<Id>>,
+
+    /// Campaigning for and results of elections.
+    #[serde(default)]
+    pub elections: Elections,
+
+    /// Requests NPCs have made from their agendas.
+    #[serde(default)]
+    pub agendas: Agendas,
 }
 
 impl Default for State {
@@ -77,7 +173,7 @@ impl Default for State {
 
 impl State {
     pub fn new(mut world: World) -> State {
-        let mut npcs = NPC::load();
+        let mut npcs = world.npcs.clone();
         let n_npcs = npcs.iter().filter(|npc| !npc.locked).count() as f32;
         for npc in npcs.iter_mut() {
             if !npc.locked {
@@ -94,6 +190,7 @@ impl State {
 
         let events = world.events.clone();
         let death_year = world.year + LIFESPAN;
+        let elections = Elections::new(world.year);
 
         let resources = Reserve::from(world.starting_resources);
         let feedstocks = Reserve::from(world.feedstock_reserves);
@@ -103,14 +200,21 @@ impl State {
             world,
             political_capital: 100,
             research_points: 0,
+            ledger: Ledger::default(),
             death_year,
             resources,
             feedstocks,
 
-            protected_land: 0.1, // Starts at 10%
+            land: Land::default(),
 
             events: vec![],
             event_pool: EventPool::new(events),
+            storylines: BTreeMap::new(),
+            tipping_points: BTreeMap::new(),
+            synergies: BTreeMap::new(),
+            applied_regions: BTreeMap::new(),
+            elections,
+            agendas: Agendas::default(),
 
             runs: 0,
             game_over: false,
@@ -118,6 +222,7 @@ impl State {
             last_outlook: 0.,
             shortages_outlook: 0.,
             emissions: Emissions::default(),
+            carbon_budget: CarbonBudget::default(),
             produced: Production::default(),
             output_demand: OutputDemand::default(),
             resource_demand: ResourceDemand::default(),
@@ -126,12 +231,23 @@ impl State {
             flags: vec![],
             requests: vec![],
             policy_queue: vec![],
+            vote_bargains: BTreeMap::new(),
+            emigrating: vec![],
+            disaster_impacts: BTreeMap::new(),
+            prerequisites_met: vec![],
         };
         state.initialize();
         state
     }
 
     fn initialize(&mut self) {
+        for project in self.world.projects.iter_mut() {
+            if !project.prerequisites.is_empty() {
+                project.locked = true;
+            }
+        }
+        self.unlock_projects();
+        self.update_synergies();
         self.last_outlook = self.outlook();
         self.update_demand();
         self.step_production();
@@ -153,16 +269,30 @@ impl State {
             || self.emissions.as_gtco2eq() <= 0.
     }
 
-    pub fn apply_disaster(&mut self, intensity: isize, region_id: &Id) {
-        self.world.regions[region_id].base_habitability -= intensity as f32;
+    /// Strike a region with a disaster, returning what it did.
+    pub fn apply_disaster(&mut self, event: &IconEvent, region_id: &Id) -> DisasterImpact {
+        let region = &mut self.world.regions[region_id];
+        let impact = DisasterImpact::of(event, region);
+        region.damage(impact.damage);
+        region.population -= impact.deaths;
+        region.outlook -= impact.outlook;
+        *self.disaster_impacts.entry(*region_id).or_default() += impact;
+        impact
     }
 
     pub fn outlook(&self) -> f32 {
         self.world.outlook() - self.shortages_outlook
     }
 
-    pub fn change_political_capital(&mut self, amount: isize) {
+    pub fn change_political_capital(&mut self, amount: isize, source: Source) {
         self.political_capital += amount;
+        self.ledger
+            .record(Currency::PoliticalCapital, source, amount);
+    }
+
+    pub fn change_research_points(&mut self, amount: isize, source: Source) {
+        self.research_points += amount;
+        self.ledger.record(Currency::Research, source, amount);
     }
 
     pub fn collect_research_points(&mut self) -> isize {
@@ -178,8 +308,14 @@ impl State {
             if changes.completed {
                 updates.push(Update::Project { id });
             }
+            if let Some(setback) = changes.setback {
+                updates.push(Update::Setback { id, setback });
+            }
             self.apply_changes(changes);
         }
+        for id in self.unlock_projects() {
+            updates.push(Update::ProjectUnlocked { id });
+        }
 
         self.update_demand();
         self.step_production();
@@ -187,6 +323,11 @@ impl State {
         updates.extend(self.step_world(tgav));
         self.world.year += 1;
 
+        let year = self.world.year;
+        for npc in self.npcs.iter_mut().filter(|npc| !npc.locked) {
+            npc.fade_relationship(year);
+        }
+
         // We actually apply policy changes the year
         // before so that any impacts they have are
         // accounted in the production phase of the last
@@ -225,6 +366,8 @@ impl State {
         for (effect, region_id) in effects {
             effect.apply(self, region_id);
         }
+
+        self.update_storylines(event_id, region_id);
     }
 
     pub fn eval_conditions(&self, conditions: &[Condition], region_id: Option<Id>) -> bool {
@@ -237,6 +380,7 @@ impl State {
 
     fn apply_changes<C: Changes>(&mut self, changes: C) {
         changes.apply(self);
+        self.update_synergies();
         self.update_demand();
     }
 
@@ -308,9 +452,17 @@ impl State {
         let total_demand = self.output_demand.total();
         let orders = self.world.processes.orders(&total_demand);
 
-        // Apply land protection
-        self.resources.available.land =
-            self.world.starting_resources.land * (1. - self.protected_land);
+        // Apply land protection and other reserved land
+        let total_land = self.world.starting_resources.land;
+        self.resources.available.land = total_land * self.land.available();
+
+        // Share out the land between outputs
+        let mut land_required = outputs!();
+        for order in &orders {
+            let land = order.process.adj_resources().land;
+            land_required[order.process.output] += land * order.amount;
+        }
+        let land_budgets = self.land.budgets(total_land, &land_required);
 
         // Run production function
         let (
@@ -324,10 +476,17 @@ impl State {
             (&required_resources, &required_feedstocks),
             &self.resources.available,
             &self.feedstocks.available,
+            &land_budgets,
         );
 
-        self.produced.by_process = produced_by_process;
-        self.produced.amount = produced_by_type;
+        // Disasters disrupt production
+        // until the regions recover.
+        let disruption = 1. - self.world.regions.disruption();
+        self.produced.by_process = produced_by_process
+            .into_iter()
+            .map(|(id, amount)| (id, amount * disruption))
+            .collect();
+        self.produced.amount = produced_by_type * disruption;
 
         resource_demand.water += required_resources.water;
         resource_demand.land += required_resources.land;
@@ -342,7 +501,8 @@ impl State {
         self.feedstocks.consumed = consumed_feedstocks;
         self.feedstocks.required = required_feedstocks;
 
-        self.byproducts.base = production_byproducts + industry_byproducts;
+        self.byproducts.base =
+            production_byproducts + industry_byproducts + self.world.independent_byproducts();
         self.emissions.update(self.byproducts.total());
     }
 
@@ -371,7 +531,8 @@ impl State {
                 / 4.))
             .max(0.);
 
-        self.world.update_extinction_rate(&self.produced.by_process);
+        self.land.used = self.world.land_usage(&self.produced.by_process);
+        self.world.update_extinction_rate(&self.produced.by_process, &self.land);
     }
 
     fn step_world(&mut self, tgav: f32) -> Vec<Update> {
@@ -379,18 +540,39 @@ impl State {
             self.game_over = true;
         }
 
+        self.carbon_budget.add_year(self.emissions.co2 * 1e-15, self.emissions.as_gtco2eq());
         self.world.update_populations();
+        self.land.transition();
         let temp_change = self.world.update_climate(tgav);
+        let tipped = self.update_tipping_points();
 
         let stop = self.flags.contains(&Flag::StopDevelopment);
         let fast = self.flags.contains(&Flag::FastDevelopment);
         let degrow = self.flags.contains(&Flag::Degrowth);
         let (regions_up, regions_down) = self.world.regions.develop(stop, fast, degrow);
 
-        let wretched_ally = self.npcs.is_ally("The Fanonist");
-        let consumerist_ally = self.npcs.is_ally("The Consumerist");
-        self.world
-            .update_outlook(temp_change, wretched_ally, consumerist_ally);
+        self.world.update_outlook(temp_change, &self.npcs);
+
+        let closed_borders = self.flags.contains(&Flag::ClosedBorders);
+        let flows = self.world.update_migration(closed_borders);
+        let migrations = significant_emigration(&flows, &self.world.regions);
+
+        // Only report regions that have started losing people.
+        let prev_emigrating = std::mem::take(&mut self.emigrating);
+        self.emigrating = migrations.iter().map(|(from, _, _)| *from).collect();
+        let migrations = migrations
+            .into_iter()
+            .filter(|(from, _, _)| !prev_emigrating.contains(from));
+
+        // Only report years of disasters with a significant toll.
+        let disasters: Vec<_> = std::mem::take(&mut self.disaster_impacts)
+            .into_iter()
+            .filter(|(id, impact)| {
+                let population = self.world.regions[id].population;
+                impact.deaths >= population * SIGNIFICANT_DISASTER_DEATHS
+            })
+            .collect();
+
         regions_up
             .into_iter()
             .map(|id| Update::Region { id, up: true })
@@ -399,15 +581,149 @@ impl State {
                     .into_iter()
                     .map(|id| Update::Region { id, up: false }),
             )
+            .chain(migrations.map(|(from, to, amount)| Update::Migration { from, to, amount }))
+            .chain(tipped.into_iter().map(|id| Update::TippingPoint { id }))
+            .chain(disasters.into_iter().map(|(id, impact)| Update::Disaster { id, impact }))
             .collect()
     }
 
+    /// Check for tipping points that have tipped this year
+    /// and apply their feedbacks, returning their ids.
+    pub fn update_tipping_points(&mut self) -> Vec<Id> {
+        let year = self.world.year;
+        let at_risk: Vec<bool> = self
+            .world
+            .tipping_points
+            .iter()
+            .map(|point| point.is_at_risk(self))
+            .collect();
+        let tipped: Vec<TippingPoint> = self
+            .world
+            .tipping_points
+            .iter()
+            .zip(at_risk)
+            .filter(|(point, at_risk)| {
+                let progress = self.tipping_points.entry(point.id).or_default();
+                progress.update(point, *at_risk, year)
+            })
+            .map(|(point, _)| point.clone())
+            .collect();
+
+        for point in &tipped {
+            for effect in &point.effects {
+                effect.apply(self, None);
+            }
+            if let Some(event_id) = point.event {
+                self.event_pool.queue_event(event_id, None, 1);
+            }
+        }
+        tipped.into_iter().map(|point| point.id).collect()
+    }
+
     // Every planning cycle
     pub fn finish_cycle(&mut self) {
         let outlook_change = self.outlook() - self.last_outlook;
         let recent_projects: Vec<&Project> = self.world.projects.recent(self.world.year).collect();
         self.npcs.update_seats(outlook_change, &recent_projects);
         self.last_outlook = self.outlook();
+
+        // Older saves start counting from the first cycle after loading.
+        let year = self.world.year;
+        self.elections.last.get_or_insert(year);
+
+        // No elections while parliament is suspended.
+        if self.elections.is_due(year, self.world.election_interval) {
+            if self.flags.contains(&Flag::ParliamentSuspended) {
+                self.elections.skip(year);
+            } else {
+                self.hold_election();
+            }
+        }
+
+        self.update_agendas();
+    }
+
+    /// How content the regions are, from -1 to 1,
+    /// which is what the player's allies are judged on.
+    pub fn election_mood(&self) -> f32 {
+        (self.world.regions.outlook() / 10.).clamp(-1., 1.)
+    }
+
+    pub fn hold_election(&mut self) -> ElectionResult {
+        let mood = self.election_mood();
+        self.elections.hold(&mut self.npcs, mood, self.world.year)
+    }
+
+    /// Spend political capital campaigning for a faction
+    /// in the next election. Returns `false` if there's no
+    /// upcoming election, the faction can't be campaigned
+    /// for anymore, or there isn't enough political capital.
+    pub fn campaign(&mut self, npc_id: &Id) -> bool {
+        let can_campaign = self.world.election_interval > 0
+            && self.npcs.try_get(npc_id).is_some_and(|npc| !npc.locked)
+            && self.elections.can_campaign(npc_id)
+            && self.political_capital >= CAMPAIGN_COST as isize;
+        if can_campaign {
+            self.change_political_capital(-(CAMPAIGN_COST as isize), Source::Campaign);
+            self.elections.campaign(npc_id);
+        }
+        can_campaign
+    }
+
+    /// Pay the upkeep of online projects for the planning cycle.
+    /// Projects that can't be paid for stall, losing their effects
+    /// until they're paid for again, and are halted if they stay
+    /// unpaid for too long.
+    ///
+    /// Research upkeep is paid from `research_points`, so any
+    /// points already collected for the player to spend
+    /// need to be handed back first.
+    pub fn pay_upkeep(&mut self) {
+        let due: Vec<(Id, Upkeep)> = self
+            .world
+            .projects
+            .iter()
+            .filter(|project| project.is_online() || project.is_stalled())
+            .filter_map(|project| project.upkeep.map(|upkeep| (project.id, upkeep)))
+            .collect();
+        for (id, upkeep) in due {
+            let (funds, currency) = match upkeep {
+                Upkeep::PoliticalCapital(_) => {
+                    (&mut self.political_capital, Currency::PoliticalCapital)
+                }
+                Upkeep::Research(_) => (&mut self.research_points, Currency::Research),
+            };
+            let cost = upkeep.amount() as isize;
+            let project = &mut self.world.projects[&id];
+            if *funds >= cost {
+                *funds -= cost;
+                self.ledger.record(currency, Source::Upkeep, -cost);
+                let changes = project.resume();
+                self.apply_changes(changes);
+            } else if project.is_stalled() {
+                project.stalled_cycles += 1;
+                if project.stalled_cycles > MAX_STALLED_CYCLES {
+                    self.stop_project(&id);
+                }
+            } else {
+                let changes = project.stall();
+                self.apply_changes(changes);
+            }
+        }
+    }
+
+    /// The total upkeep due next planning cycle,
+    /// as political capital and research points.
+    pub fn upkeep_due(&self) -> (usize, usize) {
+        self.world
+            .projects
+            .iter()
+            .filter(|project| project.is_online() || project.is_stalled())
+            .filter_map(|project| project.upkeep)
+            .fold((0, 0), |(pc, research), upkeep| match upkeep {
+                Upkeep::PoliticalCapital(amount) => (pc + amount, research),
+                Upkeep::Research(amount) => (pc, research + amount),
+            })
     }
 
     pub fn check_requests(&mut self) -> Vec<(Request, Id, bool, usize)> {
@@ -415,21 +731,11 @@ impl State {
         let mut completed = Vec::new();
         while i < self.requests.len() {
             let (kind, id, active, bounty) = self.requests[i].clone();
-            let complete = match kind {
-                Request::Project => {
-                    let project = &self.world.projects[&id];
-                    (active
-                        && (project.status == Status::Active || project.status == Status::Finished))
-                        || (!active
-                            && (project.status == Status::Inactive
-                                || project.status == Status::Halted))
-                }
-                Request::Process => {
-                    let process = &self.world.processes[&id];
-                    (active && process.is_promoted()) || (!active && process.is_banned())
-                }
+            let demand = match kind {
+                Request::Project => Demand::Project(id, active),
+                Request::Process => Demand::Process(id, active),
             };
-            if complete {
+            if demand.is_met(self) {
                 self.requests.remove(i);
                 completed.push((kind, id, active, bounty));
             } else {
@@ -439,6 +745,52 @@ impl State {
         completed
     }
 
+    /// Have NPCs make requests from their agendas,
+    /// returning the new requests.
+    pub fn update_agendas(&mut self) -> Vec<AgendaRequest> {
+        let year = self.world.year;
+        let mut new = vec![];
+        for npc in self.npcs.unlocked() {
+            if !self.agendas.can_request(&npc.id, year) {
+                continue;
+            }
+            let item = npc.agenda.iter().find(|item| {
+                !item.demand.is_met(self) && self.eval_conditions(&item.conditions, None)
+            });
+            if let Some(item) = item {
+                new.push((npc.id, item.clone()));
+            }
+        }
+        new.into_iter()
+            .map(|(npc_id, item)| self.agendas.add(npc_id, &item, year))
+            .collect()
+    }
+
+    /// Close agenda requests that have been fulfilled or
+    /// have run out of time. Fulfilled requests improve the
+    /// relationship with the NPC and ignored ones cost it;
+    /// the bounties are paid out with the report.
+    pub fn check_agenda_requests(&mut self) -> ClosedRequests {
+        let year = self.world.year;
+        let mut closed = ClosedRequests::default();
+        for request in self.agendas.requests.clone() {
+            let npc_id = request.npc_id;
+            if request.demand.is_met(self) {
+                let reason = Reason::RequestFulfilled(request.demand.clone());
+                self.npcs[&npc_id].change_relationship(RELATIONSHIP_CHANGE_AMOUNT, reason, year);
+                closed.fulfilled.push(request);
+            } else if request.is_expired(year) {
+                let reason = Reason::RequestIgnored(request.demand.clone());
+                self.npcs[&npc_id].change_relationship(-request.penalty, reason, year);
+                closed.expired.push(request);
+            } else {
+                continue;
+            }
+            self.agendas.close(npc_id, year);
+        }
+        closed
+    }
+
     pub fn change_process_mix_share(&mut self, process_id: &Id, change: isize) {
         let changes = self.world.processes[process_id].change_mix_share(change);
         self.apply_changes(changes);
@@ -451,6 +803,8 @@ impl State {
     }
 
     pub fn roll_events(&mut self, phase: Phase) -> Vec<ResolvedEvent> {
+        self.advance_storylines();
+
         let mut pool = self.event_pool.clone();
         let events = pool.roll_for_phase(phase, self);
         self.event_pool = pool;
@@ -476,10 +830,115 @@ impl State {
     }
 }
 
+// Storyline related functionality.
+impl State {
+    /// Start or progress any storylines
+    /// whose current stage is for this event.
+    fn update_storylines(&mut self, event_id: Id, region_id: Option<Id>) {
+        for storyline in self.world.storylines.iter() {
+            let progress = self.storylines.entry(storyline.id).or_default();
+            if progress.finished || progress.awaiting {
+                continue;
+            }
+            let stage = progress.stage.unwrap_or(0);
+            if storyline.stages.get(stage).is_some_and(|stage| stage.event == event_id) {
+                if progress.stage.is_none() {
+                    progress.stage = Some(0);
+                    progress.history.push(0);
+                }
+                progress.awaiting = true;
+                progress.shown = false;
+                progress.response = None;
+                progress.region = region_id;
+            }
+        }
+    }
+
+    /// Record the dialogue response selected for an event,
+    /// so storylines can branch on it.
+    pub fn record_response(&mut self, event_id: Id, response_id: usize) {
+        for storyline in self.world.storylines.iter() {
+            if let Some(progress) = self.storylines.get_mut(&storyline.id)
+                && progress.awaiting
+                && progress
+                    .stage
+                    .and_then(|idx| storyline.stages.get(idx))
+                    .is_some_and(|stage| stage.event == event_id)
+            {
+                progress.response = Some(response_id);
+            }
+        }
+    }
+
+    /// Mark an event as having been shown to the player,
+    /// so storylines waiting on it can branch.
+    pub fn finish_event(&mut self, event_id: Id) {
+        for storyline in self.world.storylines.iter() {
+            if let Some(progress) = self.storylines.get_mut(&storyline.id)
+                && progress.awaiting
+                && progress
+                    .stage
+                    .and_then(|idx| storyline.stages.get(idx))
+                    .is_some_and(|stage| stage.event == event_id)
+            {
+                progress.shown = true;
+            }
+        }
+    }
+
+    /// Move storylines whose stage event has been shown to their
+    /// next stage, queuing that stage's event. The queued event
+    /// still needs to pass its own probability roll, but
+    /// stays queued until it does.
+    pub fn advance_storylines(&mut self) {
+        let mut queue = vec![];
+        for storyline in self.world.storylines.iter() {
+            let Some(progress) = self.storylines.get(&storyline.id) else {
+                continue;
+            };
+            if !progress.awaiting || !progress.shown {
+                continue;
+            }
+
+            let next = storyline.next_stage(self, progress);
+            let progress = self.storylines.get_mut(&storyline.id).unwrap();
+            progress.awaiting = false;
+            progress.shown = false;
+            progress.response = None;
+            match next {
+                Some((idx, delay)) => {
+                    progress.stage = Some(idx);
+                    progress.history.push(idx);
+                    queue.push((storyline.stages[idx].event, progress.region, delay));
+                }
+                None => progress.finished = true,
+            }
+        }
+
+        for (event_id, region_id, delay) in queue {
+            if let Some(event) = self.event_pool.events.try_get_mut(&event_id) {
+                // Allow stages to revisit earlier events.
+                event.occurred = false;
+                self.event_pool.queue_persistent(event_id, region_id, delay.max(1));
+            }
+        }
+    }
+}
+
 // Project related functionality.
 impl State {
     fn step_projects(&mut self) -> Vec<(Id, ProjectChanges)> {
-        let mut changes = self.world.projects.step(self.world.year);
+        let setbacks: BTreeMap<Id, Setback> = self
+            .world
+            .projects
+            .iter()
+            .filter(|project| project.is_building() && !project.is_policy())
+            .filter_map(|project| {
+                let setback = self.construction_risk(project).roll()?;
+                Some((project.id, setback))
+            })
+            .collect();
+        let mut changes = self.world.projects.step(self.world.year, &setbacks);
 
         let mut outcomes: Vec<(Id, usize)> = Vec::new();
         for (id, changes) in &mut changes {
@@ -503,62 +962,195 @@ impl State {
         changes
     }
 
-    fn update_project_costs(&mut self) {
-        let base_modifier = self.base_project_cost_modifier();
-        let total_demand = self.output_demand.total();
-        let income_level = self.world.regions.income_level();
+    /// The project's chances of setbacks while it's being built,
+    /// made worse by labor sabotage, unrest in the regions
+    /// it's being built in, and factions opposing it.
+    pub fn construction_risk(&self, project: &Project) -> Risk {
+        let mut multiplier = 1.;
+        if self.flags.contains(&Flag::LaborSabotage) {
+            multiplier += LABOR_SABOTAGE_RISK;
+        }
 
-        let posadist_ally = self.npcs.is_ally("The Posadist");
-        let utopian_ally = self.npcs.is_ally("The Utopian");
-        let animal_ally = self.npcs.is_ally("The Animal Liberationist");
-        let environ_ally = self.npcs.is_ally("The Environmentalist");
-        let ecofem_ally = self.npcs.is_ally("The Ecofeminist");
-        let malthus_ally = self.npcs.is_ally("The Malthusian");
+        let regions: Vec<_> = self
+            .world
+            .regions
+            .iter()
+            .filter(|region| project.targets(region))
+            .collect();
+        if !regions.is_empty() {
+            let unrest = regions.iter().filter(|region| region.outlook < 0.).count();
+            multiplier += unrest as f32 / regions.len() as f32;
+        }
 
-        for project in self.world.projects.iter_mut() {
-            let mut group_modifier = 1.0;
-            if posadist_ally && project.group == Group::Nuclear {
-                group_modifier *= 0.5;
-            }
-            if ecofem_ally
-                && matches!(
-                    project.group,
-                    Group::Food | Group::Agriculture | Group::Protection
-                )
-            {
-                group_modifier *= 0.75;
-            }
-            if utopian_ally
-                && matches!(
-                    project.group,
-                    Group::Limits | Group::Protection | Group::Restoration
-                )
-            {
-                group_modifier *= 0.75;
-            }
-            if environ_ally && project.group == Group::Protection {
-                group_modifier *= 0.5;
+        let opposition = project
+            .opposers
+            .iter()
+            .filter(|id| !self.npcs[*id].locked && self.npcs[*id].relation() != NPCRelation::Ally)
+            .count();
+        multiplier += opposition as f32 * OPPOSITION_RISK;
+
+        project.risk.scale(multiplier)
+    }
+
+    /// The range of years the project will likely take
+    /// to finish, from no setbacks to a likely worst case.
+    pub fn construction_estimate(&self, project: &Project) -> (usize, usize) {
+        let years = if project.is_building() {
+            project.years_remaining()
+        } else if project.points > 0 {
+            project.estimate
+        } else {
+            project.cost
+        };
+        let risk = self.construction_risk(project);
+        (years, risk.likely_years(years))
+    }
+
+    /// Apply or remove the effects of synergies as their
+    /// projects come online or go offline, returning
+    /// the ids of any that became active.
+    pub fn update_synergies(&mut self) -> Vec<Id> {
+        let mut activated = vec![];
+        let mut changes = vec![];
+        for synergy in self.world.synergies.iter() {
+            let effects = if synergy.is_active(&self.world.projects) {
+                synergy.effects(&self.world.projects)
+            } else {
+                vec![]
+            };
+            let applied = self.synergies.get(&synergy.id);
+            if applied.map_or(effects.is_empty(), |applied| *applied == effects) {
+                continue;
             }
-            if animal_ally && project.group == Group::Food {
-                group_modifier *= 0.5;
+
+            if applied.is_none() {
+                activated.push(synergy.id);
             }
-            if malthus_ally && project.group == Group::Population {
-                group_modifier *= 0.5;
+            let mut change = synergy.changes(&self.world.projects);
+            change.remove_effects = applied.cloned().unwrap_or_default();
+            change.add_effects = effects.clone();
+            changes.push((synergy.id, change, effects));
+        }
+
+        for (id, change, effects) in changes {
+            change.apply(self);
+            if effects.is_empty() {
+                self.synergies.remove(&id);
+            } else {
+                self.synergies.insert(id, effects);
             }
+        }
+        activated
+    }
+
+    /// Unlock projects whose prerequisites have all been met,
+    /// returning their ids. Each project is only unlocked
+    /// this way once so that other effects can still lock it.
+    pub fn unlock_projects(&mut self) -> Vec<Id> {
+        let ready: Vec<Id> = self
+            .world
+            .projects
+            .iter()
+            .filter(|project| {
+                !project.prerequisites.is_empty()
+                    && !self.prerequisites_met.contains(&project.id)
+                    && project
+                        .prerequisites
+                        .iter()
+                        .all(|prereq| prereq.is_met(self))
+            })
+            .map(|project| project.id)
+            .collect();
+        for id in &ready {
+            self.world.projects[id].locked = false;
+            self.prerequisites_met.push(*id);
+        }
+        ready
+    }
+
+    /// Regions targeted by a project,
+    /// or `None` if the project isn't targeted.
+    pub fn target_regions(&self, target: Option<Target>, chosen: Option<Id>) -> Option<Vec<Id>> {
+        target.map(|target| {
+            self.world
+                .regions
+                .iter()
+                .filter(|region| !region.seceded && target.matches(region, chosen))
+                .map(|region| region.id)
+                .collect()
+        })
+    }
+
+    /// Regions a project's or synergy's effects apply to:
+    /// where they were first applied, if they still are,
+    /// otherwise the regions it currently targets.
+    fn applied_regions(
+        &self,
+        id: &Id,
+        target: Option<Target>,
+        chosen: Option<Id>,
+    ) -> Option<Vec<Id>> {
+        self.applied_regions
+            .get(id)
+            .cloned()
+            .or_else(|| self.target_regions(target, chosen))
+    }
+
+    /// Targeted projects cost in proportion to
+    /// the share of the population they target.
+    fn target_cost_scale(&self, project: &Project) -> f32 {
+        let Some(target) = project.target else {
+            return 1.;
+        };
+        let total = self.world.regions.population();
+        let targeted: f32 = if project.needs_target_region() {
+            // Estimate with the average region.
+            total / self.world.regions.len() as f32
+        } else {
+            self.world
+                .regions
+                .iter()
+                .filter(|region| target.matches(region, project.target_region))
+                .map(|region| region.population)
+                .sum()
+        };
+        (targeted / total).max(MIN_TARGET_COST_SCALE)
+    }
+
+    /// Choose the region a project targets.
+    pub fn set_project_target(&mut self, project_id: &Id, region_id: &Id) {
+        self.world.projects[project_id].target_region = Some(*region_id);
+        self.update_project_costs();
+    }
+
+    fn update_project_costs(&mut self) {
+        let base_modifier = self.base_project_cost_modifier();
+        let base_costs: Vec<(usize, f32)> = self
+            .world
+            .projects
+            .iter()
+            .map(|project| {
+                (
+                    project.base_cost.base_amount(self, project),
+                    self.target_cost_scale(project),
+                )
+            })
+            .collect();
+
+        for (project, (base_cost, target_scale)) in self.world.projects.iter_mut().zip(base_costs) {
+            let mut group_modifier = self.npcs.project_cost_modifier(project.group);
             if self.flags.contains(&Flag::EcosystemModeling) && project.group == Group::Restoration
             {
                 group_modifier *= 1.1;
             }
             project.update_cost(
-                self.world.year,
-                income_level,
-                &total_demand,
+                base_cost,
                 // Modifier only relevant for built projects,
                 // not policies.
                 if project.kind == ProjectType::Policy {
-                    1.0
+                    target_scale
                 } else {
-                    base_modifier * group_modifier
+                    base_modifier * group_modifier * target_scale
                 },
             );
             project.update_required_majority(&self.npcs);
@@ -596,6 +1188,42 @@ impl State {
         if is_policy {
             self.policy_queue.push(*project_id);
         }
+        self.vote_bargains.remove(project_id);
+    }
+
+    /// Simulate how parliament would vote on the project.
+    pub fn simulate_vote(&self, project_id: &Id) -> VoteResult {
+        let bargained = self
+            .vote_bargains
+            .get(project_id)
+            .map(|ids| ids.as_slice())
+            .unwrap_or_default();
+        simulate_vote(
+            &self.world.projects[project_id],
+            &self.npcs,
+            bargained,
+            self.flags.contains(&Flag::ParliamentSuspended),
+        )
+    }
+
+    /// Spend political capital to win over a faction
+    /// for the project's vote. Returns `false` if the faction
+    /// won't bargain or there isn't enough political capital.
+    pub fn bargain_vote(&mut self, project_id: &Id, npc_id: &Id) -> bool {
+        let cost = self
+            .simulate_vote(project_id)
+            .factions
+            .iter()
+            .find(|faction| faction.npc_id == *npc_id)
+            .and_then(|faction| faction.bargain_cost);
+        match cost {
+            Some(cost) if cost as isize <= self.political_capital => {
+                self.change_political_capital(-(cost as isize), Source::Bargain);
+                self.vote_bargains.entry(*project_id).or_default().push(*npc_id);
+                true
+            }
+            _ => false,
+        }
     }
 
     pub fn stop_project(&mut self, project_id: &Id) {
@@ -606,6 +1234,48 @@ impl State {
         self.apply_changes(changes);
     }
 
+    /// Repeal a policy, or roll back its latest upgrade,
+    /// as long as it isn't locked in. Only a full repeal
+    /// carries the policy's political fallout.
+    /// Returns `false` if it can't be repealed or
+    /// there isn't enough political capital.
+    pub fn repeal_policy(&mut self, project_id: &Id) -> bool {
+        let project = &self.world.projects[project_id];
+        if !project.can_repeal(self.world.year) {
+            return false;
+        }
+        if project.can_downgrade() {
+            self.downgrade_project(project_id);
+            return true;
+        }
+
+        let repeal = project.repeal;
+        if repeal.cost as isize > self.political_capital {
+            return false;
+        }
+        self.change_political_capital(-(repeal.cost as isize), Source::Repeal);
+
+        let (mut changes, _) = self.world.projects[project_id].stop();
+        let project = &self.world.projects[project_id];
+        let reason = Reason::PolicyRepealed(*project_id);
+        for npc_id in &project.supporters {
+            changes
+                .relationships
+                .push((*npc_id, -repeal.supporter_backlash, reason.clone()));
+        }
+        for npc_id in &project.opposers {
+            changes
+                .relationships
+                .push((*npc_id, repeal.opposer_approval, reason.clone()));
+        }
+        self.apply_changes(changes);
+
+        if repeal.outlook != 0. {
+            Effect::WorldVariable(WorldVariable::Outlook, repeal.outlook).apply(self, None);
+        }
+        true
+    }
+
     pub fn upgrade_project(&mut self, project_id: &Id) {
         let changes = self.world.projects[project_id].upgrade();
         self.apply_changes(changes);
@@ -639,27 +1309,35 @@ impl State {
     }
 
     fn roll_new_policy_outcomes(&mut self) -> Vec<Update> {
-        let mut effects: Vec<Effect> = Vec::new();
+        let mut effects: Vec<(Effect, Option<Id>)> = Vec::new();
         let ids: Vec<Id> = self.policy_queue.drain(..).collect();
         for id in &ids {
             let mut active_outcome = None;
+            let mut policy_effects = vec![];
             let proj = &self.world.projects[id];
             if let Some((outcome, i)) = self.roll_project_outcome(proj) {
-                for effect in &outcome.effects {
-                    effects.push(effect.clone());
-                }
+                policy_effects.extend(outcome.effects.iter().cloned());
                 active_outcome = Some(i);
             }
+            let regions = self.applied_regions(id, proj.target, proj.target_region);
+            if let Some(regions) = &regions {
+                self.applied_regions.insert(*id, regions.clone());
+            }
             let proj = &mut self.world.projects[id];
             proj.active_outcome = active_outcome;
             proj.status = Status::Active;
-            for effect in &proj.effects {
-                effects.push(effect.clone());
+            proj.completed_at = self.world.year;
+            policy_effects.extend(proj.effects.iter().cloned());
+
+            for effect in policy_effects {
+                for region_id in effect_regions(&effect, &regions) {
+                    effects.push((effect.clone(), region_id));
+                }
             }
         }
 
-        for effect in effects {
-            effect.apply(self, None);
+        for (effect, region_id) in effects {
+            effect.apply(self, region_id);
         }
         self.update_demand();
 
@@ -723,23 +1401,56 @@ impl Emissions {
 pub trait Changes {
     fn apply(self, state: &mut State);
 }
+
+/// Regional effects apply to each of the targeted regions,
+/// everything else applies once.
+fn effect_regions(effect: &Effect, regions: &Option<Vec<Id>>) -> Vec<Option<Id>> {
+    match regions {
+        Some(regions) if effect.is_regional() => regions.iter().copied().map(Some).collect(),
+        _ => vec![None],
+    }
+}
 impl Changes for ProcessChanges {
     fn apply(self, state: &mut State) {
-        for (id, change) in self.relationships {
-            state.npcs[&id].relationship += change;
+        let year = state.world.year;
+        for (id, change, reason) in self.relationships {
+            state.npcs[&id].change_relationship(change, reason, year);
         }
     }
 }
 impl Changes for ProjectChanges {
     fn apply(self, state: &mut State) {
+        let regions = state.applied_regions(&self.id, self.target, self.target_region);
+        let adding = !self.add_effects.is_empty();
         for effect in self.remove_effects {
-            effect.unapply(state, None);
+            for region_id in effect_regions(&effect, &regions) {
+                effect.unapply(state, region_id);
+            }
         }
         for effect in self.add_effects {
-            effect.apply(state, None);
+            for region_id in effect_regions(&effect, &regions) {
+                effect.apply(state, region_id);
+            }
+        }
+
+        // Forget the regions once the effects are all removed.
+        let active = match state.world.projects.try_get(&self.id) {
+            Some(project) => project.is_online() || project.is_building(),
+            None => adding,
+        };
+        match regions {
+            Some(regions) if active && adding => {
+                state.applied_regions.insert(self.id, regions);
+            }
+            _ if !active => {
+                state.applied_regions.remove(&self.id);
+            }
+            _ => {}
         }
-        for (id, change) in self.relationships {
-            state.npcs[&id].relationship += change;
+
+        let year = state.world.year;
+        for (id, change, reason) in self.relationships {
+            state.npcs[&id].change_relationship(change, reason, year);
         }
     }
 }
@@ -768,6 +1479,36 @@ pub enum Update {
     Project {
         id: Id,
     },
+
+    /// A project whose prerequisites have all been met.
+    ProjectUnlocked {
+        id: Id,
+    },
+    Migration {
+        from: Id,
+
+        /// Where most of the migrants went.
+        to: Id,
+
+        /// Total number of people who left.
+        amount: f32,
+    },
+    TippingPoint {
+        id: Id,
+    },
+    Setback {
+        /// The project that ran into trouble.
+        id: Id,
+
+        setback: Setback,
+    },
+    Disaster {
+        /// The region struck.
+        id: Id,
+
+        /// What this year's disasters did to it.
+        impact: DisasterImpact,
+    },
 }
 
 impl Update {
@@ -790,6 +1531,18 @@ impl Update {
     pub fn is_policy(&self) -> bool {
         matches!(self, Update::Policy { .. })
     }
+
+    pub fn is_migration(&self) -> bool {
+        matches!(self, Update::Migration { .. })
+    }
+
+    pub fn is_tipping_point(&self) -> bool {
+        matches!(self, Update::TippingPoint { .. })
+    }
+
+    pub fn is_disaster(&self) -> bool {
+        matches!(self, Update::Disaster { .. })
+    }
 }
 
 // #[cfg(test)]
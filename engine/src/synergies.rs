@@ -0,0 +1,238 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use strum::{Display, EnumDiscriminants, EnumIter, EnumString, IntoStaticStr};
+
+use crate::{
+    Collection, HasId, Id,
+    events::Effect,
+    projects::{Project, ProjectChanges},
+};
+
+/// What happens while all of a synergy's projects are online.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumIter, EnumString, IntoStaticStr, Display))]
+#[strum_discriminants(name(InteractionKind))]
+pub enum Interaction {
+    /// These effects are added.
+    Effects(Vec<Effect>),
+
+    /// The effects of one of the projects are scaled,
+    /// e.g. 0.5 halves them and 2 doubles them.
+    Scale(Id, f32),
+}
+
+impl Interaction {
+    pub fn from_kind(kind: InteractionKind, default_project: Id) -> Self {
+        match kind {
+            InteractionKind::Effects => Self::Effects(vec![]),
+            InteractionKind::Scale => Self::Scale(default_project, 0.5),
+        }
+    }
+}
+
+/// A rule for how projects work together,
+/// or against each other, when they're all online.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Synergy {
+    pub id: Id,
+    pub name: String,
+
+    /// The projects that all have to be online.
+    pub projects: Vec<Id>,
+
+    pub interaction: Interaction,
+
+    pub notes: String,
+}
+impl Default for Synergy {
+    fn default() -> Self {
+        Self {
+            id: Id::new_v4(),
+            name: "New Synergy".into(),
+            projects: vec![],
+            interaction: Interaction::Effects(vec![]),
+            notes: "".into(),
+        }
+    }
+}
+
+impl Display for Synergy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl HasId for Synergy {
+    fn id(&self) -> &Id {
+        &self.id
+    }
+}
+
+impl Synergy {
+    /// All the projects this depends on,
+    /// including any whose effects are scaled.
+    pub fn project_ids(&self) -> Vec<Id> {
+        let mut ids = self.projects.clone();
+        if let Interaction::Scale(id, _) = self.interaction
+            && !ids.contains(&id)
+        {
+            ids.push(id);
+        }
+        ids
+    }
+
+    pub fn involves(&self, project_id: &Id) -> bool {
+        self.project_ids().contains(project_id)
+    }
+
+    /// If this works against its projects
+    /// rather than with them.
+    pub fn is_conflict(&self) -> bool {
+        matches!(self.interaction, Interaction::Scale(_, scale) if scale < 1.)
+    }
+
+    pub fn is_active(&self, projects: &Collection<Project>) -> bool {
+        let ids = self.project_ids();
+        !ids.is_empty()
+            && ids.iter().all(|id| {
+                projects
+                    .try_get(id)
+                    .is_some_and(|project| project.is_online())
+            })
+    }
+
+    /// The extra effects this adds while active.
+    pub fn effects(&self, projects: &Collection<Project>) -> Vec<Effect> {
+        match &self.interaction {
+            Interaction::Effects(effects) => effects.clone(),
+            Interaction::Scale(id, scale) => projects
+                .try_get(id)
+                .map(|project| {
+                    project
+                        .active_effects_with_outcomes()
+                        .into_iter()
+                        .filter(|effect| effect.is_scalable())
+                        .map(|effect| effect.clone() * (scale - 1.))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Scaled effects follow the targeting of their project.
+    pub(crate) fn changes(&self, projects: &Collection<Project>) -> ProjectChanges {
+        let project = match self.interaction {
+            Interaction::Scale(id, _) => projects.try_get(&id),
+            Interaction::Effects(_) => None,
+        };
+        ProjectChanges {
+            id: self.id,
+            target: project.and_then(|p| p.target),
+            target_region: project.and_then(|p| p.target_region),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        events::{PlayerVariable, WorldVariable},
+        projects::Status,
+        state::State,
+    };
+
+    #[test]
+    fn test_synergies() {
+        let mut state = State::default();
+        let ids: Vec<Id> = state
+            .world
+            .projects
+            .iter()
+            .filter(|p| p.target.is_none())
+            .take(2)
+            .map(|p| p.id)
+            .collect();
+        let (a, b) = (ids[0], ids[1]);
+        state.world.projects[&a].effects = vec![Effect::WorldVariable(WorldVariable::Outlook, -4.)];
+        state.world.synergies = Collection::from(vec![
+            Synergy {
+                projects: vec![a, b],
+                interaction: Interaction::Effects(vec![Effect::PlayerVariable(
+                    PlayerVariable::PoliticalCapital,
+                    10.,
+                )]),
+                ..Default::default()
+            },
+            Synergy {
+                projects: vec![b],
+                interaction: Interaction::Scale(a, 0.5),
+                ..Default::default()
+            },
+        ]);
+        let outlook = state.world.base_outlook;
+        let political_capital = state.political_capital;
+
+        state.world.projects[&a].status = Status::Finished;
+        assert!(state.update_synergies().is_empty());
+        assert_eq!(state.political_capital, political_capital);
+
+        state.world.projects[&b].status = Status::Active;
+        assert_eq!(state.update_synergies().len(), 2);
+        assert_eq!(state.political_capital, political_capital + 10);
+        assert_eq!(state.world.base_outlook, outlook + 2.);
+
+        // Nothing changes if the projects don't.
+        assert!(state.update_synergies().is_empty());
+        assert_eq!(state.political_capital, political_capital + 10);
+
+        state.world.projects[&b].status = Status::Halted;
+        state.update_synergies();
+        assert_eq!(state.political_capital, political_capital);
+        assert_eq!(state.world.base_outlook, outlook);
+        assert!(state.synergies.is_empty());
+    }
+
+    #[test]
+    fn test_synergy_regions() {
+        use crate::{events::LocalVariable, projects::Target};
+
+        let mut state = State::default();
+        let ids: Vec<Id> = state.world.projects.iter().take(2).map(|p| p.id).collect();
+        let (a, b) = (ids[0], ids[1]);
+        let region_ids: Vec<Id> = state.world.regions.iter().map(|r| r.id).collect();
+        let outlooks: Vec<f32> = state.world.regions.iter().map(|r| r.outlook).collect();
+
+        let project = &mut state.world.projects[&a];
+        project.effects = vec![Effect::LocalVariable(LocalVariable::Outlook, 1.)];
+        project.upgrades.clear();
+        project.level = 0;
+        project.active_outcome = None;
+        project.target = Some(Target::Region);
+        project.target_region = Some(region_ids[0]);
+        project.status = Status::Finished;
+        state.world.projects[&b].status = Status::Active;
+        state.world.synergies = Collection::from(vec![Synergy {
+            projects: vec![b],
+            interaction: Interaction::Scale(a, 2.),
+            ..Default::default()
+        }]);
+
+        // Scaled effects only apply to the project's targeted region.
+        state.update_synergies();
+        assert_eq!(
+            state.world.regions[&region_ids[0]].outlook,
+            outlooks[0] + 1.
+        );
+        assert_eq!(state.world.regions[&region_ids[1]].outlook, outlooks[1]);
+
+        // And are removed from it even if the target changes.
+        state.world.projects[&a].target_region = Some(region_ids[1]);
+        state.world.projects[&b].status = Status::Halted;
+        state.update_synergies();
+        for (region, outlook) in state.world.regions.iter().zip(outlooks) {
+            assert_eq!(region.outlook, outlook);
+        }
+    }
+}
@@ -0,0 +1,359 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumDiscriminants, EnumIter, EnumString, IntoStaticStr};
+
+use crate::{
+    Collection, Id,
+    events::{Comparator, Condition, Effect, Flag, WorldVariable},
+    projects::{Project, Status},
+    state::State,
+};
+
+/// Something that has to happen before
+/// a project becomes available.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumIter, EnumString, IntoStaticStr, Display))]
+#[strum_discriminants(name(PrerequisiteKind))]
+pub enum Prerequisite {
+    /// Another project has reached this status.
+    /// Active and finished are treated as the same.
+    ProjectStatus(Id, Status),
+
+    /// Another project has at least this many upgrades.
+    ProjectLevel(Id, usize),
+
+    /// This flag has been set.
+    HasFlag(Flag),
+
+    /// The game has reached this year.
+    Year(usize),
+}
+
+impl Prerequisite {
+    pub fn from_kind(kind: PrerequisiteKind, default_project: Id) -> Self {
+        match kind {
+            PrerequisiteKind::ProjectStatus => {
+                Self::ProjectStatus(default_project, Status::Finished)
+            }
+            PrerequisiteKind::ProjectLevel => Self::ProjectLevel(default_project, 1),
+            PrerequisiteKind::HasFlag => Self::HasFlag(Flag::Vegan),
+            PrerequisiteKind::Year => Self::Year(2030),
+        }
+    }
+
+    pub fn project_id(&self) -> Option<Id> {
+        match self {
+            Prerequisite::ProjectStatus(id, ..) | Prerequisite::ProjectLevel(id, ..) => Some(*id),
+            _ => None,
+        }
+    }
+
+    /// The equivalent event condition.
+    pub fn as_condition(&self) -> Condition {
+        match self {
+            Prerequisite::ProjectStatus(id, status) => Condition::ProjectStatus(*id, *status),
+            Prerequisite::ProjectLevel(id, level) => {
+                Condition::ActiveProjectUpgrades(*id, Comparator::GreaterEqual, *level)
+            }
+            Prerequisite::HasFlag(flag) => Condition::HasFlag(*flag),
+            Prerequisite::Year(year) => Condition::WorldVariable(
+                WorldVariable::Year,
+                Comparator::GreaterEqual,
+                *year as f32,
+            ),
+        }
+    }
+
+    pub fn is_met(&self, state: &State) -> bool {
+        self.as_condition().eval(state, None)
+    }
+}
+
+/// How one project depends on another.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DependencyKind {
+    /// Declared as a prerequisite.
+    Prerequisite,
+
+    /// Unlocked by the other project's effects.
+    Unlock,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dependency {
+    pub project: Id,
+    pub kind: DependencyKind,
+}
+
+/// The graph of which projects lead to which,
+/// built from both declared prerequisites and
+/// `UnlocksProject` effects.
+#[derive(Debug, Default, Clone)]
+pub struct ProjectGraph {
+    dependencies: BTreeMap<Id, Vec<Dependency>>,
+}
+
+impl ProjectGraph {
+    pub fn new(projects: &Collection<Project>) -> Self {
+        let mut dependencies: BTreeMap<Id, Vec<Dependency>> = BTreeMap::new();
+        let mut add = |id: Id, dep: Dependency| {
+            let deps = dependencies.entry(id).or_default();
+            if !deps.contains(&dep) {
+                deps.push(dep);
+            }
+        };
+
+        for project in projects.iter() {
+            for id in project
+                .prerequisites
+                .iter()
+                .filter_map(Prerequisite::project_id)
+            {
+                add(
+                    project.id,
+                    Dependency {
+                        project: id,
+                        kind: DependencyKind::Prerequisite,
+                    },
+                );
+            }
+
+            let effects = project
+                .effects
+                .iter()
+                .chain(project.outcomes.iter().flat_map(|o| o.effects.iter()))
+                .chain(project.upgrades.iter().flat_map(|u| u.effects.iter()));
+            for effect in effects {
+                if let Effect::UnlocksProject(id) = effect {
+                    add(
+                        *id,
+                        Dependency {
+                            project: project.id,
+                            kind: DependencyKind::Unlock,
+                        },
+                    );
+                }
+            }
+        }
+        Self { dependencies }
+    }
+
+    /// The projects this project depends on.
+    pub fn dependencies(&self, id: &Id) -> &[Dependency] {
+        self.dependencies
+            .get(id)
+            .map_or(&[], |deps| deps.as_slice())
+    }
+
+    /// The projects that depend on this project.
+    pub fn dependents(&self, id: &Id) -> Vec<Dependency> {
+        self.dependencies
+            .iter()
+            .flat_map(|(dependent, deps)| {
+                deps.iter()
+                    .filter(|dep| dep.project == *id)
+                    .map(|dep| Dependency {
+                        project: *dependent,
+                        kind: dep.kind,
+                    })
+            })
+            .collect()
+    }
+
+    /// How far down the tree a project is, i.e. the length
+    /// of its longest chain of dependencies.
+    /// Projects with no dependencies are at tier 0.
+    pub fn tier(&self, id: &Id) -> usize {
+        self.tier_of(id, &mut BTreeMap::new(), &mut BTreeSet::new())
+    }
+
+    /// Group projects by their tier.
+    pub fn tiers(&self, ids: impl Iterator<Item = Id>) -> Vec<Vec<Id>> {
+        let mut memo = BTreeMap::new();
+        let mut tiers: Vec<Vec<Id>> = vec![];
+        for id in ids {
+            let tier = self.tier_of(&id, &mut memo, &mut BTreeSet::new());
+            if tiers.len() <= tier {
+                tiers.resize(tier + 1, vec![]);
+            }
+            tiers[tier].push(id);
+        }
+        tiers
+    }
+
+    fn tier_of(
+        &self,
+        id: &Id,
+        memo: &mut BTreeMap<Id, usize>,
+        visiting: &mut BTreeSet<Id>,
+    ) -> usize {
+        if let Some(tier) = memo.get(id) {
+            return *tier;
+        }
+
+        // Unlocks can legitimately loop back on each other,
+        // so stop following a chain once it comes around.
+        if !visiting.insert(*id) {
+            return 0;
+        }
+        let tier = self
+            .dependencies(id)
+            .iter()
+            .map(|dep| self.tier_of(&dep.project, memo, visiting) + 1)
+            .max()
+            .unwrap_or(0);
+        visiting.remove(id);
+        memo.insert(*id, tier);
+        tier
+    }
+
+    /// Find a loop of prerequisites, which would
+    /// mean none of the projects in it can ever be unlocked.
+    /// Loops of unlocks are fine as any one
+    /// of them is enough to unlock the next.
+    pub fn find_cycle(&self) -> Option<Vec<Id>> {
+        let mut done = BTreeSet::new();
+        for id in self.dependencies.keys() {
+            let mut path = vec![];
+            if let Some(cycle) = self.find_cycle_from(id, &mut path, &mut done) {
+                return Some(cycle);
+            }
+        }
+        None
+    }
+
+    fn find_cycle_from(
+        &self,
+        id: &Id,
+        path: &mut Vec<Id>,
+        done: &mut BTreeSet<Id>,
+    ) -> Option<Vec<Id>> {
+        if let Some(i) = path.iter().position(|other| other == id) {
+            return Some(path[i..].to_vec());
+        }
+        if done.contains(id) {
+            return None;
+        }
+
+        path.push(*id);
+        let prereqs = self
+            .dependencies(id)
+            .iter()
+            .filter(|dep| dep.kind == DependencyKind::Prerequisite);
+        for dep in prereqs {
+            if let Some(cycle) = self.find_cycle_from(&dep.project, path, done) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        done.insert(*id);
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::state::Update;
+
+    fn gen_project(prerequisites: Vec<Prerequisite>, effects: Vec<Effect>) -> Project {
+        Project {
+            id: Id::new_v4(),
+            prerequisites,
+            effects,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_project_graph() {
+        let a = gen_project(vec![], vec![]);
+        let b = gen_project(
+            vec![Prerequisite::ProjectStatus(a.id, Status::Finished)],
+            vec![],
+        );
+        let c = gen_project(
+            vec![
+                Prerequisite::ProjectLevel(b.id, 1),
+                Prerequisite::Year(2040),
+            ],
+            vec![],
+        );
+        let d = gen_project(vec![], vec![Effect::UnlocksProject(c.id)]);
+        let (a_id, b_id, c_id, d_id) = (a.id, b.id, c.id, d.id);
+        let projects = Collection::from(vec![a, b, c, d]);
+
+        let graph = ProjectGraph::new(&projects);
+        assert_eq!(graph.dependencies(&a_id), &[]);
+        assert_eq!(graph.dependencies(&c_id).len(), 2);
+        assert_eq!(
+            graph.dependents(&d_id),
+            vec![Dependency {
+                project: c_id,
+                kind: DependencyKind::Unlock,
+            }]
+        );
+        assert_eq!(graph.tier(&a_id), 0);
+        assert_eq!(graph.tier(&c_id), 2);
+        let tiers = graph.tiers(projects.iter().map(|p| p.id));
+        assert_eq!(tiers, vec![vec![a_id, d_id], vec![b_id], vec![c_id]]);
+        assert_eq!(graph.find_cycle(), None);
+
+        // Unlock loops are fine...
+        let mut projects = projects;
+        projects[&a_id].effects.push(Effect::UnlocksProject(d_id));
+        projects[&d_id].effects.push(Effect::UnlocksProject(a_id));
+        let graph = ProjectGraph::new(&projects);
+        assert_eq!(graph.find_cycle(), None);
+
+        // ...but prerequisite loops aren't.
+        projects[&a_id]
+            .prerequisites
+            .push(Prerequisite::ProjectStatus(c_id, Status::Active));
+        let graph = ProjectGraph::new(&projects);
+        let cycle = graph.find_cycle().unwrap();
+        assert_eq!(cycle.len(), 3);
+        assert!(cycle.contains(&a_id) && cycle.contains(&b_id) && cycle.contains(&c_id));
+    }
+
+    #[test]
+    fn test_unlock_projects() {
+        let mut state = State::default();
+        let ids: Vec<Id> = state.world.projects.iter().take(2).map(|p| p.id).collect();
+        let (a, b) = (ids[0], ids[1]);
+        let year = state.world.year;
+        state.world.projects[&a].status = Status::Building;
+        state.world.projects[&b].locked = true;
+        state.world.projects[&b].prerequisites = vec![
+            Prerequisite::ProjectStatus(a, Status::Finished),
+            Prerequisite::Year(year),
+        ];
+        assert!(state.unlock_projects().is_empty());
+
+        state.world.projects[&a].status = Status::Finished;
+        assert_eq!(state.unlock_projects(), vec![b]);
+        assert!(!state.world.projects[&b].locked);
+
+        // Other effects can still lock it again.
+        state.world.projects[&b].locked = true;
+        assert!(state.unlock_projects().is_empty());
+        assert!(state.world.projects[&b].locked);
+    }
+
+    #[test]
+    fn test_unlock_updates() {
+        let mut state = State::default();
+        let ids: Vec<Id> = state.world.projects.iter().take(2).map(|p| p.id).collect();
+        let (a, b) = (ids[0], ids[1]);
+        state.world.projects[&b].locked = true;
+        state.world.projects[&b].prerequisites =
+            vec![Prerequisite::ProjectStatus(a, Status::Finished)];
+        state.world.projects[&a].status = Status::Finished;
+
+        // The player is told about projects unlocked over the year.
+        let tgav = state.world.temperature;
+        let updates = state.step_year(tgav);
+        assert!(updates.contains(&Update::ProjectUnlocked { id: b }));
+    }
+}
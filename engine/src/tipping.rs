@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+use crate::{
+    HasId, Id,
+    events::{Condition, Effect},
+    flavor::Image,
+    state::State,
+};
+
+/// A climate tipping element, e.g. permafrost or an ice sheet.
+/// Once the temperature stays past its threshold for long enough
+/// it tips, irreversibly applying its feedbacks.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TippingPoint {
+    pub id: Id,
+    pub name: String,
+    pub description: String,
+    pub image: Image,
+
+    /// The global temperature anomaly (C)
+    /// past which this element is at risk.
+    pub threshold: f32,
+
+    /// Other conditions that must also hold
+    /// for a year to count towards tipping.
+    #[serde(default)]
+    pub conditions: Vec<Condition>,
+
+    /// How many consecutive years the temperature
+    /// has to stay past the threshold for this to tip.
+    pub years: usize,
+
+    /// Feedbacks applied when this tips.
+    /// These are never unapplied.
+    pub effects: Vec<Effect>,
+
+    /// An event to trigger when this tips.
+    pub event: Option<Id>,
+
+    pub notes: String,
+}
+impl Default for TippingPoint {
+    fn default() -> Self {
+        Self {
+            id: Id::new_v4(),
+            name: "New Tipping Point".into(),
+            description: "".into(),
+            image: Image::default(),
+            threshold: 2.,
+            conditions: vec![],
+            years: 10,
+            effects: vec![],
+            event: None,
+            notes: "".into(),
+        }
+    }
+}
+
+impl Display for TippingPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl HasId for TippingPoint {
+    fn id(&self) -> &Id {
+        &self.id
+    }
+}
+
+impl TippingPoint {
+    /// If the temperature is past the threshold
+    /// and the other conditions hold.
+    pub fn is_at_risk(&self, state: &State) -> bool {
+        state.world.temperature >= self.threshold && state.eval_conditions(&self.conditions, None)
+    }
+}
+
+/// How close a tipping point is to tipping.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TippingProgress {
+    /// Consecutive years spent past the threshold.
+    pub overshoot: usize,
+
+    /// The year this tipped, if it has.
+    pub tipped: Option<usize>,
+}
+impl TippingProgress {
+    /// Update for whether the point is at risk this year,
+    /// returning true if it just tipped.
+    pub fn update(&mut self, point: &TippingPoint, at_risk: bool, year: usize) -> bool {
+        if self.tipped.is_some() {
+            return false;
+        }
+        if at_risk {
+            self.overshoot += 1;
+        } else {
+            self.overshoot = 0;
+        }
+        if self.overshoot >= point.years {
+            self.tipped = Some(year);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn has_tipped(&self) -> bool {
+        self.tipped.is_some()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{State, events::WorldVariable};
+
+    #[test]
+    fn test_tipping_progress() {
+        let point = TippingPoint {
+            threshold: 2.,
+            years: 3,
+            ..Default::default()
+        };
+        let mut progress = TippingProgress::default();
+
+        // Brief overshoots don't tip.
+        assert!(!progress.update(&point, true, 2030));
+        assert!(!progress.update(&point, true, 2031));
+        assert!(!progress.update(&point, false, 2032));
+        assert_eq!(progress.overshoot, 0);
+
+        // Sustained ones do.
+        assert!(!progress.update(&point, true, 2033));
+        assert!(!progress.update(&point, true, 2034));
+        assert!(progress.update(&point, true, 2035));
+        assert_eq!(progress.tipped, Some(2035));
+
+        // And there's no going back.
+        assert!(!progress.update(&point, false, 2036));
+        assert!(progress.has_tipped());
+    }
+
+    #[test]
+    fn test_tipping_feedbacks() {
+        let mut state = State::default();
+        state.world.tipping_points = vec![TippingPoint {
+            threshold: 2.,
+            years: 1,
+            effects: vec![Effect::WorldVariable(WorldVariable::SeaLevelRiseRate, 0.01)],
+            ..Default::default()
+        }]
+        .into_iter()
+        .collect();
+
+        state.world.temperature = 1.5;
+        assert!(state.update_tipping_points().is_empty());
+
+        state.world.temperature = 2.5;
+        let modifier = state.world.sea_level_rise_modifier;
+        assert_eq!(state.update_tipping_points().len(), 1);
+        assert_eq!(state.world.sea_level_rise_modifier, modifier + 0.01);
+
+        // Feedbacks are only applied once.
+        assert!(state.update_tipping_points().is_empty());
+        assert_eq!(state.world.sea_level_rise_modifier, modifier + 0.01);
+    }
+
+    #[test]
+    fn test_tipping_conditions() {
+        use crate::events::Comparator;
+
+        let mut state = State::default();
+        let point = TippingPoint {
+            threshold: 2.,
+            conditions: vec![Condition::WorldVariable(
+                WorldVariable::Year,
+                Comparator::Greater,
+                3000.,
+            )],
+            ..Default::default()
+        };
+        state.world.temperature = 2.5;
+        assert!(!point.is_at_risk(&state));
+
+        state.world.year = 3001;
+        assert!(point.is_at_risk(&state));
+    }
+}
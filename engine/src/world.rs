@@ -1,23 +1,38 @@
 use std::{collections::BTreeMap, sync::LazyLock};
 
 use crate::{
-    Collection, Id, OutputDemand,
-    events::Event,
+    Collection, Id, OutputDemand, byproducts, disasters, elections,
+    events::{Event, Storyline},
     industries::Industry,
-    kinds::{FeedstockMap, Output, OutputMap, ResourceMap},
+    npcs::NPC,
+    kinds::{ByproductMap, FeedstockMap, LandUseMap, Output, OutputMap, ResourceMap},
+    land::Land,
+    migration::{self, MigrationFlow},
     outputs,
     production::Process,
     projects::Project,
     regions::{Income, Region},
     round_to,
+    synergies::Synergy,
+    tech::ProjectGraph,
+    tipping::TippingPoint,
 };
 use serde::{Deserialize, Serialize};
 
+/// How much region outlook recovers each year.
+const BASE_OUTLOOK_RECOVERY: f32 = 0.1;
+
 pub static CLIMATES: LazyLock<BTreeMap<String, Vec<[f32; 4]>>> = LazyLock::new(|| {
     let data = include_str!("../assets/climates.json");
     serde_json::from_str(data).unwrap()
 });
 
+/// Roughly standard normal noise for yearly
+/// climate variability, as a sum of uniform draws.
+fn climate_noise() -> f32 {
+    (0..12).map(|_| fastrand::f32()).sum::<f32>() - 6.
+}
+
 /// The `World` represents a game configuration,
 /// defining the world's parameters as well
 /// as the projects, processes, regions, and industries.
@@ -33,6 +48,12 @@ pub struct World {
     pub sea_level_rise: f32, // meters
     pub temperature_modifier: f32,
     pub population_growth_modifier: f32,
+
+    /// How much faster (or slower) regions move
+    /// through the demographic transition.
+    #[serde(default)]
+    pub demographic_transition_modifier: f32,
+
     pub sea_level_rise_modifier: f32, // meters
     pub precipitation: f32,           // global precip avg
 
@@ -40,7 +61,6 @@ pub struct World {
     pub per_capita_demand: [OutputDemand; 4],
     pub water_by_income: [f32; 4],
     pub materials_by_income: [f32; 4],
-    pub income_pop_coefs: [[f32; 4]; 4],
 
     pub industries: Collection<Industry>,
     pub projects: Collection<Project>,
@@ -48,6 +68,26 @@ pub struct World {
     pub project_lockers: BTreeMap<Id, Id>,
     pub events: Collection<Event>,
 
+    #[serde(default)]
+    pub storylines: Collection<Storyline>,
+
+    /// Climate tipping elements and their feedbacks.
+    #[serde(default)]
+    pub tipping_points: Collection<TippingPoint>,
+
+    /// Rules for how projects work together or against each other.
+    #[serde(default)]
+    pub synergies: Collection<Synergy>,
+
+    /// The NPCs for this world.
+    #[serde(default = "NPC::load")]
+    pub npcs: Collection<NPC>,
+
+    /// How many years there are between elections,
+    /// or zero for no elections.
+    #[serde(default = "elections::default_interval")]
+    pub election_interval: usize,
+
     pub feedstock_reserves: FeedstockMap,
     pub starting_resources: ResourceMap,
 }
@@ -64,6 +104,7 @@ impl World {
         self.temperature = tgav + self.temperature_modifier;
         let temp_change = prev_temp - self.temperature;
         self.regions.update_climates(tgav);
+        self.regions.recover();
         self.sea_level_rise += self.sea_level_rise_rate();
         temp_change
     }
@@ -71,8 +112,7 @@ impl World {
     pub fn update_outlook(
         &mut self,
         temp_change: f32,
-        wretched_ally: bool,
-        consumerist_ally: bool,
+        npcs: &Collection<NPC>,
     ) {
         let temp_outlook = temp_change * 6. * self.temperature.max(0.).powf(2.);
         let region_outlook_change = temp_outlook * 0.4;
@@ -81,38 +121,64 @@ impl World {
         }
         self.base_outlook += temp_outlook;
         self.temp_outlook += temp_outlook;
-        self.regions.update_outlook(wretched_ally, consumerist_ally);
+        self.regions.update_outlook(npcs);
     }
 
     pub fn outlook(&self) -> f32 {
         self.base_outlook + self.regions.outlook()
     }
 
+    /// How the projects depend on each other.
+    pub fn project_graph(&self) -> ProjectGraph {
+        ProjectGraph::new(&self.projects)
+    }
+
     pub fn update_populations(&mut self) {
         for region in self.regions.iter_mut() {
             region.update_pop(
-                self.year as f32,
+                self.sea_level_rise,
                 1. + self.population_growth_modifier,
-                &self.income_pop_coefs,
+                self.demographic_transition_modifier,
             );
         }
     }
 
-    pub fn update_extinction_rate(&mut self, produced_by_process: &BTreeMap<Id, f32>) {
+    /// People migrate between regions
+    /// towards better living conditions.
+    pub fn update_migration(&mut self, closed_borders: bool) -> Vec<MigrationFlow> {
+        migration::migrate(&mut self.regions, self.sea_level_rise, closed_borders)
+    }
+
+    pub fn update_extinction_rate(
+        &mut self,
+        produced_by_process: &BTreeMap<Id, f32>,
+        land: &Land,
+    ) {
         let base = self.tgav_extinction_rate() + self.slr_extinction_rate();
         let lic_pop = self.lic_population();
         let from_processes = self.processes.iter().fold(0., |acc, p| {
             let amount = produced_by_process.get(&p.id).unwrap_or(&0.);
-            let contrib = p.extinction_rate(self.starting_resources.land) * amount;
-            acc + contrib
+            acc + p.biodiversity_pressure() * amount
         });
         let from_industries = self.industries.iter().fold(0., |acc, ind| {
-            acc + ind.extinction_rate(self.starting_resources.land) * ind.demand(lic_pop)
+            acc + ind.biodiversity_pressure() * ind.demand(lic_pop)
         });
-        let rate = base + from_industries + from_processes;
+        let rate = base + from_industries + from_processes + land.extinction_rate();
         self.extinction_rate = rate;
     }
 
+    /// Share of land used by processes, by the output they produce,
+    /// and by industries, which counts as urban land.
+    pub fn land_usage(&self, produced_by_process: &BTreeMap<Id, f32>) -> LandUseMap {
+        let mut usage = LandUseMap::default();
+        for process in self.processes.iter() {
+            let amount = produced_by_process.get(&process.id).unwrap_or(&0.);
+            usage[Land::use_for_output(process.output)] += process.adj_resources().land * amount;
+        }
+        usage.urban += self.industries.resource_demand(self.lic_population()).land;
+        usage / self.starting_resources.land
+    }
+
     /// Contribution to extinction rate from the tgav
     pub fn tgav_extinction_rate(&self) -> f32 {
         self.temperature.max(0.).powf(2.)
@@ -129,18 +195,48 @@ impl World {
         (0.0025 * self.temperature.max(0.).powf(1.5)) + self.sea_level_rise_modifier
     }
 
+    /// Low-income capita population of the regions
+    /// that are still part of Gosplant.
     pub fn lic_population(&self) -> f32 {
         self.regions
             .iter()
+            .filter(|r| !r.seceded)
             .map(|r| r.lic_population(&self.materials_by_income))
             .sum()
     }
 
+    /// Demand of the regions that are still part of Gosplant.
     pub fn region_demand(&self) -> OutputMap {
-        self.regions.iter().fold(outputs!(), |mut acc, region| {
-            acc += region.demand(&self.per_capita_demand);
-            acc
-        })
+        self.regions
+            .iter()
+            .filter(|r| !r.seceded)
+            .fold(outputs!(), |mut acc, region| {
+                acc += region.demand(&self.per_capita_demand);
+                acc
+            })
+    }
+
+    /// Byproducts of seceded regions, which meet
+    /// their own demand with their own process mixes.
+    /// Their emissions still affect the climate.
+    pub fn independent_byproducts(&self) -> ByproductMap {
+        self.regions
+            .iter()
+            .filter(|r| r.seceded)
+            .fold(byproducts!(), |acc, region| {
+                let lic_pop = region.lic_population(&self.materials_by_income);
+                let mut demand = region.demand(&self.per_capita_demand);
+                let industry_demand = self.industries.resource_demand(lic_pop);
+                demand.fuel = industry_demand.fuel;
+                demand.electricity = industry_demand.electricity;
+
+                let from_processes = self.processes.iter().fold(byproducts!(), |acc, process| {
+                    let share = region.process_mix.get(&process.id).copied().unwrap_or(0);
+                    let amount = demand[process.output] * share as f32 * 0.05;
+                    acc + process.adj_byproducts() * amount
+                });
+                acc + from_processes + self.industries.byproducts(lic_pop)
+            })
     }
 
     pub fn demand_by_income_levels(&self, output: Output) -> [f32; 4] {
@@ -176,9 +272,35 @@ impl Collection<Region> {
         self.iter().map(|r| r.population).sum()
     }
 
-    fn update_outlook(&mut self, wretched_ally: bool, consumerist_ally: bool) {
+    /// Share of production disrupted by disasters,
+    /// weighted by the population of each region
+    /// that still produces for the world.
+    pub fn disruption(&self) -> f32 {
+        let (disrupted, population) = self
+            .iter()
+            .filter(|r| !r.seceded)
+            .fold((0., 0.), |(disrupted, population), r| {
+                (disrupted + disasters::disruption(r) * r.population, population + r.population)
+            });
+        if population > 0. {
+            disrupted / population
+        } else {
+            0.
+        }
+    }
+
+    fn update_outlook(&mut self, npcs: &Collection<NPC>) {
+        for region in self.iter_mut() {
+            let recovery = npcs
+                .region_outlook_recovery(region.income)
+                .unwrap_or(BASE_OUTLOOK_RECOVERY);
+            region.update_outlook(recovery);
+        }
+    }
+
+    fn recover(&mut self) {
         for region in self.iter_mut() {
-            region.update_outlook(wretched_ally, consumerist_ally);
+            region.recover();
         }
     }
 
@@ -188,11 +310,10 @@ impl Collection<Region> {
         let tgav = round_to(temp, 1);
         let key = format!("{tgav:.1}");
         if let Some(climates) = CLIMATES.get(&key) {
-            for (region, vals) in self.iter_mut().zip(climates) {
-                region.temp_lo = vals[0];
-                region.temp_hi = vals[1];
-                region.precip_lo = vals[2];
-                region.precip_hi = vals[3];
+            let baseline = &CLIMATES["0.0"];
+            for (region, (vals, base)) in self.iter_mut().zip(climates.iter().zip(baseline)) {
+                let noise = (climate_noise(), climate_noise());
+                region.update_climate(vals, base, temp, noise);
             }
         }
     }
@@ -203,8 +324,11 @@ impl Collection<Region> {
     }
 
     /// Mean habitability of all regions.
-    pub fn habitability(&self) -> f32 {
-        self.iter().map(|r| r.habitability()).sum::<f32>() / self.len() as f32
+    pub fn habitability(&self, sea_level_rise: f32) -> f32 {
+        self.iter()
+            .map(|r| r.habitability(sea_level_rise))
+            .sum::<f32>()
+            / self.len() as f32
     }
 
     /// Mean income level of all regions.
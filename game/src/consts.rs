@@ -31,10 +31,6 @@ pub const MAX_POINTS: usize = 12;
 pub const MAX_BIODIVERSITY: f32 = 120.;
 pub const MAX_CONTENTEDNESS: f32 = 40.;
 
-/// Factor to compute contentedness change resulting from an
-/// icon/world event, by its intensity.
-pub const EVENT_INTENSITY_TO_CONTENTEDNESS: f32 = 0.1;
-
 /// PC earned per intensity level of contentedness.
 pub const CONTENTEDNESS_PC: [isize; 6] = [0, 0, 5, 10, 20, 30];
 
@@ -94,8 +94,9 @@ impl DebugOpts {
             state.world.temperature = 0.;
         }
         if self.region_seceded {
+            let processes = &state.world.processes;
             let region = state.world.regions.get_mut(0).unwrap();
-            region.seceded = true;
+            region.secede(processes);
         }
         if self.feedstock_shortage {
             state.world.feedstock_reserves.oil = 0.;
@@ -274,6 +274,39 @@ pub fn flag_tip(flag: Flag, demand: &OutputMap) -> Tip {
     }
 }
 
+/// Describe what an NPC is asking for.
+pub fn demand_text(demand: &NPCDemand, state: &State) -> String {
+    match demand {
+        NPCDemand::Project(id, active) => {
+            let name = t!(&state.world.projects[id].name);
+            if *active {
+                t!("Implement %{name}", name = name)
+            } else {
+                t!("Stop %{name}", name = name)
+            }
+        }
+        NPCDemand::Process(id, active) => {
+            let name = t!(&state.world.processes[id].name);
+            if *active {
+                t!("Unban %{name}", name = name)
+            } else {
+                t!("Ban %{name}", name = name)
+            }
+        }
+        NPCDemand::Flag(flag) => t!("Make this happen: %{flag}", flag = t!(flag.to_string())),
+        NPCDemand::Protection(share) => t!(
+            "Protect %{percent}% of land",
+            percent = (share * 100.).round()
+        ),
+        NPCDemand::Output(output, share) => t!(
+            "Meet %{percent}% of %{output} demand",
+            percent = (share * 100.).round(),
+            output = t!(output.lower())
+        ),
+    }
+    .to_string()
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DisplayEffect {
     pub effect: Effect,
@@ -477,6 +510,22 @@ impl DisplayEffect {
                         ),
                     ),
                 ),
+                WorldVariable::DemographicTransition => (
+                    tip(
+                        icons::POPULATION,
+                        t!(
+                            "As regions develop, birth and death rates fall and population growth levels off."
+                        ),
+                    ),
+                    icon_text(
+                        "population",
+                        &t!(
+                            "%{changeDir} the speed of the demographic transition by %{amount}[b]%.[/b]",
+                            changeDir = self.change_dir(*amount),
+                            amount = display::percent(amount.abs(), false)
+                        ),
+                    ),
+                ),
                 WorldVariable::Population => (
                     tip(icons::POPULATION, t!("The number of people on the planet.")),
                     icon_text(
@@ -522,7 +571,34 @@ impl DisplayEffect {
                         ),
                     ),
                 ),
-                WorldVariable::Year => return Err(()),
+                WorldVariable::CumulativeEmissions
+                | WorldVariable::OnePointFiveBudget
+                | WorldVariable::TwoDegreeBudget => {
+                    // Spending the budget is the same as adding emissions.
+                    let emitted = if *var == WorldVariable::CumulativeEmissions {
+                        *amount
+                    } else {
+                        -*amount
+                    };
+                    (
+                        tip(
+                            icons::EMISSIONS,
+                            t!(
+                                "%{remaining} Gt CO2 remains in the 1.5°C carbon budget.",
+                                remaining = state.carbon_budget.remaining_1_5c().round()
+                            ),
+                        ),
+                        icon_text(
+                            "emissions",
+                            &t!(
+                                "%{changeDir} cumulative emissions by %{amount}[b]Gt[/b].",
+                                changeDir = self.change_dir(emitted),
+                                amount = self.fmt_param(emitted)
+                            ),
+                        ),
+                    )
+                }
+                WorldVariable::Year | WorldVariable::NetZeroYear => return Err(()),
             },
             Effect::PlayerVariable(var, amount) => match var {
                 PlayerVariable::ResearchPoints => (
@@ -602,6 +678,53 @@ impl DisplayEffect {
                     ),
                 ),
             ),
+            Effect::LocalVariable(var, amount) => {
+                let (icon, slug, label) = match var {
+                    LocalVariable::Outlook => {
+                        (icons::CONTENTEDNESS, "contentedness", t!("contentedness"))
+                    }
+                    LocalVariable::Habitability => {
+                        (icons::HABITABILITY, "habitability", t!("habitability"))
+                    }
+                    LocalVariable::Population => {
+                        (icons::POPULATION, "population", t!("population"))
+                    }
+                    LocalVariable::HeatwaveRisk => {
+                        (icons::HEATWAVE, "heatwave", t!("heatwave risk"))
+                    }
+                    LocalVariable::DroughtRisk => {
+                        (icons::CROP_FAILURE, "crop_failure", t!("drought risk"))
+                    }
+                    LocalVariable::FloodRisk => (icons::FLOOD, "flood", t!("flood risk")),
+                    LocalVariable::HeatResilience => {
+                        (icons::PROTECT, "protect", t!("heatwave resilience"))
+                    }
+                    LocalVariable::DroughtResilience => {
+                        (icons::PROTECT, "protect", t!("drought resilience"))
+                    }
+                    LocalVariable::FireResilience => {
+                        (icons::PROTECT, "protect", t!("wildfire resilience"))
+                    }
+                    LocalVariable::FloodResilience => {
+                        (icons::PROTECT, "protect", t!("flood resilience"))
+                    }
+                };
+                (
+                    tip(
+                        icon,
+                        t!("This only affects the regions the project or event applies to."),
+                    ),
+                    icon_text(
+                        slug,
+                        &t!(
+                            "%{changeDir} %{variable} in affected regions by %{amount}.",
+                            changeDir = self.change_dir(*amount),
+                            variable = label,
+                            amount = self.fmt_param(*amount),
+                        ),
+                    ),
+                )
+            }
             Effect::Resource(resource, amount) => {
                 let fmtted = display::resource(*amount, *resource, state.resources.available).abs();
                 let fmtted = if matches!(resource, Resource::Water | Resource::Land) {
@@ -1342,14 +1465,56 @@ impl DisplayEffect {
                     icon_text("chance", &text),
                 )
             }
+            Effect::ElectionSwing(id, amount) => {
+                let npc = &state.npcs[id];
+                let amount_label = if self.is_unknown {
+                    self.fmt_param(*amount)
+                } else {
+                    format!("{}%", display::percent(amount.abs(), true))
+                };
+                let text = t!(
+                    "%{changeDir} %{name}'s vote in elections by %{amount}.",
+                    name = t!(&npc.name),
+                    amount = amount_label,
+                    changeDir = self.change_dir(*amount),
+                );
+                (
+                    tip(
+                        icons::POLITICAL_CAPITAL,
+                        t!("This will swing every election while it lasts:"),
+                    )
+                    .card(npc.clone()),
+                    icon_text("political_capital", &text),
+                )
+            }
             Effect::AddFlag(flag) => {
                 let tip = flag_tip(*flag, &state.output_demand.total());
                 let text = format!("[b]{}[/b]", t!(flag.to_string()));
                 (tip, text)
             }
+            Effect::Reunification(progress) => {
+                let seceded = state.world.regions.iter().filter(|r| r.seceded).count();
+                (
+                    tip(
+                        icons::GOSPLANT,
+                        t!(
+                            "Negotiations with seceded regions will advance by %{amount}%. A region rejoins Gosplant once negotiations reach 100%. There are currently %{seceded} seceded regions.",
+                            amount = display::percent(*progress, true),
+                            seceded = seceded,
+                        ),
+                    ),
+                    icon_text(
+                        "gosplant",
+                        &t!(
+                            "Advance reunification talks by [b]%{percent}%[/b].",
+                            percent = display::signed_percent(*progress, true),
+                        ),
+                    ),
+                )
+            }
             Effect::ProtectLand(amount) => {
-                let before = state.protected_land;
-                let after = state.protected_land + amount;
+                let before = state.land.reserved.protected;
+                let after = before + amount;
                 (
                     tip(
                         icons::LAND,
@@ -1368,6 +1533,29 @@ impl DisplayEffect {
                     ),
                 )
             }
+            Effect::LandUse(land_use, amount) => {
+                let before = state.land.reserved[*land_use];
+                let after = before + amount;
+                (
+                    tip(
+                        icons::LAND,
+                        t!(
+                            "This will limit the amount of land that processes can use. The amount of land set aside for %{landUse} will change from %{before}% to %{after}%.",
+                            landUse = t!(land_use.lower()),
+                            before = display::percent(before, true),
+                            after = display::percent(after, true),
+                        ),
+                    ),
+                    icon_text(
+                        "land",
+                        &t!(
+                            "Change the amount of land set aside for %{landUse} by [b]%{percent}%[/b].",
+                            landUse = t!(land_use.lower()),
+                            percent = display::signed_percent(*amount, true),
+                        ),
+                    ),
+                )
+            }
             Effect::Feedstock(feedstock, amount) => {
                 let estimate = match feedstock {
                     Feedstock::Other | Feedstock::Soil => None,
@@ -1464,6 +1652,7 @@ impl DisplayEffect {
             Effect::AddEvent(..)
             | Effect::TriggerEvent(..)
             | Effect::RegionLeave
+            | Effect::RegionRejoin
             | Effect::Migration
             | Effect::AddRegionFlag(..)
             | Effect::GameOver
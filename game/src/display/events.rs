@@ -144,6 +144,14 @@ fn describe_condition(condition: &Condition, state: &State) -> Option<String> {
             WorldVariable::SeaLevelRise => Some(t!(
                 "This event is influenced by the amount of sea level rise."
             )),
+            WorldVariable::CumulativeEmissions
+            | WorldVariable::OnePointFiveBudget
+            | WorldVariable::TwoDegreeBudget => Some(t!(
+                "This event is influenced by how much of the carbon budget has been spent."
+            )),
+            WorldVariable::NetZeroYear => Some(t!(
+                "This event is influenced by how soon emissions are projected to reach net-zero."
+            )),
             _ => None,
         },
         Condition::LocalVariable(var, _, _) => match var {
@@ -153,8 +161,32 @@ fn describe_condition(condition: &Condition, state: &State) -> Option<String> {
             LocalVariable::Habitability => Some(t!(
                 "This event is influenced by the habitability of regions."
             )),
+            LocalVariable::HeatwaveRisk => Some(t!(
+                "This event is influenced by how prone regions are to heatwaves."
+            )),
+            LocalVariable::DroughtRisk => Some(t!(
+                "This event is influenced by how prone regions are to droughts."
+            )),
+            LocalVariable::FloodRisk => Some(t!(
+                "This event is influenced by how prone regions are to floods."
+            )),
+            LocalVariable::HeatResilience => Some(t!(
+                "This event is influenced by how well regions are protected from heatwaves."
+            )),
+            LocalVariable::DroughtResilience => Some(t!(
+                "This event is influenced by how well regions are protected from droughts."
+            )),
+            LocalVariable::FireResilience => Some(t!(
+                "This event is influenced by how well regions are protected from wildfires."
+            )),
+            LocalVariable::FloodResilience => Some(t!(
+                "This event is influenced by how well regions are protected from floods."
+            )),
             _ => None,
         },
+        Condition::RegionSeceded(..) | Condition::SecededRegions(..) => Some(t!(
+            "This event is influenced by regions seceding from Gosplant."
+        )),
         Condition::Demand(output, _, _) => match output {
             Output::AnimalCalories => Some(t!(
                 "This event is influenced by the demand for animal calories."
@@ -30,7 +30,9 @@ fn effects_factor<E: AsRef<Effect>>(var: Var, effects: &[E], state: &State) -> f
             .sum::<f32>(),
         Var::Land => effects
             .filter_map(|e| match e {
-                Effect::ProtectLand(val) => Some(val * state.world.starting_resources.land),
+                Effect::ProtectLand(val) | Effect::LandUse(_, val) => {
+                    Some(val * state.world.starting_resources.land)
+                }
                 _ => None,
             })
             .sum::<f32>(),
@@ -494,7 +496,7 @@ pub fn factors_card(current_name: Option<String>, var: Var, state: &State) -> Fa
             Var::Biodiversity => state.world.extinction_rate.round().max(0.),
             Var::Land => display::resource(
                 state.resource_demand.of(Resource::Land)
-                    + (state.protected_land * state.world.starting_resources.land),
+                    + (state.land.reserved.sum() * state.world.starting_resources.land),
                 Resource::Land,
                 state.world.starting_resources,
             ),
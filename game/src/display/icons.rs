@@ -1,8 +1,8 @@
 use crate::vars::Var;
 use egui::{ImageSource, TextureOptions};
 use hes_engine::{
-    Byproduct, Condition, Feedstock, LocalVariable, NPC, Output, ProcessFeature, ProjectType,
-    Resource, WorldVariable,
+    Byproduct, Condition, Feedstock, LandUse, LocalVariable, NPC, Output, ProcessFeature,
+    ProjectType, Resource, WorldVariable,
 };
 use paste::paste;
 
@@ -387,6 +387,13 @@ impl HasIcon for Condition {
                 LocalVariable::Outlook => CONTENTEDNESS,
                 LocalVariable::Habitability => HABITABILITY,
                 LocalVariable::Population => POPULATION,
+                LocalVariable::HeatwaveRisk => HEATWAVE,
+                LocalVariable::DroughtRisk => CROP_FAILURE,
+                LocalVariable::FloodRisk => FLOOD,
+                LocalVariable::HeatResilience
+                | LocalVariable::DroughtResilience
+                | LocalVariable::FireResilience
+                | LocalVariable::FloodResilience => PROTECT,
             },
             Condition::WorldVariable(var, ..) => match var {
                 WorldVariable::Temperature => WARMING,
@@ -394,13 +401,20 @@ impl HasIcon for Condition {
                 WorldVariable::SeaLevelRiseRate => SEA_LEVEL_RISE,
                 WorldVariable::Outlook => CONTENTEDNESS,
                 WorldVariable::Emissions => EMISSIONS,
+                WorldVariable::CumulativeEmissions => EMISSIONS,
+                WorldVariable::OnePointFiveBudget => EMISSIONS,
+                WorldVariable::TwoDegreeBudget => EMISSIONS,
+                WorldVariable::NetZeroYear => EMISSIONS,
                 WorldVariable::Precipitation => PRECIPITATION,
                 WorldVariable::Population => POPULATION,
                 WorldVariable::PopulationGrowth => POPULATION,
+                WorldVariable::DemographicTransition => POPULATION,
                 WorldVariable::ExtinctionRate => EXTINCTION_RATE,
                 _ => HELP,
             },
             Condition::ProtectLand(..) => PROTECT,
+            Condition::LandUse(LandUse::Protected, ..) => PROTECT,
+            Condition::LandUse(..) => LAND,
             _ => HELP,
         }
     }
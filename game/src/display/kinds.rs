@@ -27,6 +27,30 @@ impl AsText for Resource {
     }
 }
 
+impl AsText for LandUse {
+    fn lower(&self) -> &'static str {
+        match self {
+            LandUse::Cropland => "cropland",
+            LandUse::Pasture => "pasture",
+            LandUse::Energy => "energy",
+            LandUse::Urban => "urban use",
+            LandUse::Protected => "protection",
+            LandUse::Wild => "the wild",
+        }
+    }
+
+    fn title(&self) -> &'static str {
+        match self {
+            LandUse::Cropland => "Cropland",
+            LandUse::Pasture => "Pasture",
+            LandUse::Energy => "Energy",
+            LandUse::Urban => "Urban",
+            LandUse::Protected => "Protected",
+            LandUse::Wild => "Wild",
+        }
+    }
+}
+
 impl AsText for Income {
     fn lower(&self) -> &'static str {
         match self {
@@ -12,11 +12,11 @@ use std::collections::BTreeMap;
 #[ext]
 pub impl State {
     /// For total land use we have to take into account
-    /// protected land and use starting land resources as the baseline,
-    /// rather than available land (which is starting land minus protected land).
+    /// protected and other reserved land and use starting land resources as the baseline,
+    /// rather than available land (which is starting land minus reserved land).
     fn land_use_percent(&self) -> String {
         let usage = self.resource_demand.of(Resource::Land)
-            + (self.protected_land * self.world.starting_resources.land);
+            + (self.land.reserved.sum() * self.world.starting_resources.land);
         let total_land = self.world.starting_resources.land;
         let percent = usage / total_land;
         format!("{}%", display::percent(percent.min(1.0), true))
@@ -61,11 +61,9 @@ pub impl State {
             if self.flags.contains(&Flag::HyperResearch) {
                 discount += 1;
             }
-            if self.npcs.is_ally("The Accelerationist") {
-                discount += 1;
-            }
+            discount += self.npcs.research_point_discount();
         }
-        consts::POINT_COST.saturating_sub(discount) as usize
+        (consts::POINT_COST as usize).saturating_sub(discount)
     }
 
     /// Buy Research or Initiative points with PC.
@@ -82,7 +80,7 @@ pub impl State {
         } else {
             let cost = self.next_point_cost(&kind) as isize;
             if cost <= self.political_capital {
-                self.change_political_capital(-cost);
+                self.change_political_capital(-cost, LedgerSource::ProjectCost);
                 match kind {
                     ProjectType::Research => points.research += 1,
                     ProjectType::Initiative => points.initiative += 1,
@@ -106,7 +104,7 @@ pub impl State {
         let project = &self.world.projects[project_id];
         let available = self.political_capital;
         if project.status == Status::Inactive && available >= project.cost as isize {
-            self.change_political_capital(-(project.cost as isize));
+            self.change_political_capital(-(project.cost as isize), LedgerSource::ProjectCost);
             true
         } else {
             false
@@ -161,7 +159,7 @@ pub impl State {
         };
 
         if kind == ProjectType::Policy {
-            self.change_political_capital(cost as isize);
+            self.change_political_capital(cost as isize, LedgerSource::Refund);
             self.stop_project(project_id);
         }
     }
@@ -179,7 +177,7 @@ pub impl State {
         if let Some(upgrade) = upgrade {
             let available = self.political_capital;
             if !is_free && available >= upgrade.cost as isize {
-                self.change_political_capital(-(upgrade.cost as isize));
+                self.change_political_capital(-(upgrade.cost as isize), LedgerSource::ProjectCost);
             }
 
             match kind {
@@ -204,7 +202,7 @@ pub impl State {
         };
 
         if let Some(upgrade) = prev_upgrade {
-            self.change_political_capital(upgrade.cost as isize);
+            self.change_political_capital(upgrade.cost as isize, LedgerSource::Refund);
             if kind == ProjectType::Policy {
                 self.downgrade_project(project_id);
             } else {
@@ -238,10 +236,11 @@ pub impl State {
     }
 
     fn apply_disaster(&mut self, event: &IconEvent, event_id: &Id, region_id: &Id) {
-        let effect = event.intensity as f32 * consts::EVENT_INTENSITY_TO_CONTENTEDNESS;
-
-        self.apply_disaster(-effect.round() as isize, region_id);
+        self.apply_disaster(event, region_id);
         self.apply_event(*event_id, Some(*region_id));
+
+        // Disasters are shown as they happen.
+        self.finish_event(*event_id);
     }
 
     fn update_processes(&mut self, changes: &mut EnumMap<Output, BTreeMap<Id, isize>>) {
@@ -20,6 +20,10 @@ pub struct CycleStart {
     // Seats in parliament for each NPC faction
     pub parliament: Vec<f32>,
     pub completed_projects: Vec<Id>,
+
+    /// Projects unlocked by meeting their prerequisites.
+    #[serde(default)]
+    pub unlocked_projects: Vec<Id>,
 }
 
 /// Currently staged plan changes.
@@ -199,6 +203,7 @@ impl UIState {
             state.world.regions.iter().map(|r| r.income).collect();
         self.cycle_start_state.parliament = state.npcs.iter().map(|npc| npc.seats).collect();
         self.cycle_start_state.completed_projects.clear();
+        self.cycle_start_state.unlocked_projects.clear();
     }
 
     pub fn has_process_mix_changes(&self, output: Output) -> bool {
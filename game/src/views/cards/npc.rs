@@ -1,12 +1,12 @@
 use super::AsCard;
 use egui::{Color32, Stroke};
 use egui_taffy::{TuiBuilderLogic, taffy};
-use hes_engine::NPC;
+use hes_engine::{Id, NPC, RelationshipReason, State};
 use rust_i18n::t;
 
 use crate::{
     consts,
-    display::{as_speaker, icons, speaker_icon},
+    display::{self, as_speaker, icons, speaker_icon},
     parts::{flex_justified, h_center, raised_frame},
     state::GameState,
     text::{bbcode, scale_text_ui},
@@ -26,7 +26,7 @@ impl AsCard for NPC {
         Color32::WHITE
     }
 
-    fn header(&self, ui: &mut egui::Ui, _ctx: &GameState) {
+    fn header(&self, ui: &mut egui::Ui, state: &GameState) {
         egui::Frame::NONE
             .inner_margin(egui::Margin::symmetric(6, 6))
             .show(ui, |ui| {
@@ -44,13 +44,22 @@ impl AsCard for NPC {
 
                     tui.ui(|ui| {
                         let name = t!(&self.name);
-                        let tip = tip(
-                            icons::RELATIONSHIP,
-                            t!(
-                                "Your relationship with %{name}. Increase it by implementing projects they like. At 5 hearts or more they will join your coalition.",
-                                name = name
-                            ),
-                        );
+                        let mut text = t!(
+                            "Your relationship with %{name}. Increase it by implementing projects they like. At 5 hearts or more they will join your coalition.",
+                            name = name
+                        )
+                        .to_string();
+                        let reasons = self.relationship_reasons();
+                        if !reasons.is_empty() {
+                            text.push('\n');
+                        }
+                        for (reason, change) in reasons {
+                            text.push_str(&format!(
+                                "\n[b]{change:+.1}[/b] {}",
+                                reason_text(&reason, state)
+                            ));
+                        }
+                        let tip = tip(icons::RELATIONSHIP, text);
                         add_tip(
                             tip,
                             ui.horizontal(|ui| {
@@ -203,3 +212,52 @@ impl AsCard for NPC {
         });
     }
 }
+
+/// Describe why a relationship changed.
+fn reason_text(
+    reason: &RelationshipReason,
+    state: &State,
+) -> String {
+    let project = |id: &Id| {
+        t!(&state.world.projects[id].name).to_string()
+    };
+    let process = |id: &Id| {
+        t!(&state.world.processes[id].name).to_string()
+    };
+    match reason {
+        RelationshipReason::ProjectCompleted(id) => {
+            t!("Completed %{name}", name = project(id))
+        }
+        RelationshipReason::ProjectStopped(id) => {
+            t!("Stopped %{name}", name = project(id))
+        }
+        RelationshipReason::PolicyRepealed(id) => {
+            t!("Repealed %{name}", name = project(id))
+        }
+        RelationshipReason::ProcessBanned(id) => {
+            t!("Banned %{name}", name = process(id))
+        }
+        RelationshipReason::ProcessUnbanned(id) => {
+            t!("Unbanned %{name}", name = process(id))
+        }
+        RelationshipReason::ProcessPromoted(id) => {
+            t!("Promoted %{name}", name = process(id))
+        }
+        RelationshipReason::ProcessUnpromoted(id) => {
+            t!("Stopped promoting %{name}", name = process(id))
+        }
+        RelationshipReason::RequestFulfilled(demand) => t!(
+            "Fulfilled their request: %{request}",
+            request = display::demand_text(demand, state)
+        ),
+        RelationshipReason::RequestIgnored(demand) => t!(
+            "Ignored their request: %{request}",
+            request = display::demand_text(demand, state)
+        ),
+        RelationshipReason::Event => t!("Events"),
+        RelationshipReason::Standing => {
+            t!("Their general view of you")
+        }
+    }
+    .to_string()
+}
@@ -1,11 +1,11 @@
-use std::collections::BTreeMap;
-
 use crate::{
     consts,
-    display::{AsText, DisplayEffect, HasIcon, active_effects, group_color, icons, render_effects},
+    display::{
+        AsText, DisplayEffect, HasIcon, Icon, active_effects, group_color, icons, render_effects,
+    },
     image,
     parts::{flex_justified, new_icon},
-    state::{GameState, PlanChange},
+    state::GameState,
     text::scale_text_ui,
     tips::{Tip, add_tip, tip},
 };
@@ -13,7 +13,10 @@ use crate::{
 use super::AsCard;
 use egui::{Color32, Margin, Rect, Response, Stroke, TextStyle};
 use egui_taffy::{TuiBuilderLogic, taffy};
-use hes_engine::{Collection, Effect as EngineEffect, Flag, Id, NPC, Project, ProjectType};
+use hes_engine::{
+    Collection, Effect as EngineEffect, NPC, Project, ProjectType, Repeal, SynergyInteraction,
+    Upkeep,
+};
 use rust_i18n::t;
 
 impl AsCard for Project {
@@ -59,10 +62,11 @@ impl AsCard for Project {
                         } else {
                             // See other note why we need to get the canonical ref.
                             let p = &state.world.projects[&self.id];
-                            let remaining_cost = remaining_cost(p, &state.ui.plan_changes);
+                            let remaining_cost = remaining_cost(p, state);
                             let is_countdown = self.kind != ProjectType::Policy || p.is_building();
+                            let setback_chance = state.construction_risk(p).any();
                             add_tip(
-                                cost_tip(&self.kind, &remaining_cost),
+                                cost_tip(&self.kind, &remaining_cost, setback_chance),
                                 ui.horizontal_centered(|ui| {
                                     ui.style_mut().spacing.item_spacing.x = 2.;
                                     if is_countdown {
@@ -110,14 +114,7 @@ impl AsCard for Project {
         let (opposers, supporters) = npc_stances(self, &state.npcs);
         npc_support(ui, rect, &opposers, &supporters);
 
-        let parliament_suspended = state.flags.contains(&Flag::ParliamentSuspended);
-        let player_seats = state.npcs.coalition_seats();
-        let majority_satisfied = if parliament_suspended {
-            true
-        } else {
-            player_seats > self.required_majority
-        };
-        let warn_majority = self.required_majority > 0. && !majority_satisfied;
+        let warn_majority = !state.simulate_vote(&self.id).passes();
 
         if warn_majority {
             ui.place(rect, majority_warning(rect));
@@ -193,6 +190,19 @@ impl AsCard for Project {
                     }
                 };
                 let is_upgrading = state.ui.queued_upgrades.get(&self.id) == Some(&true);
+                let upkeep = self.upkeep;
+                let synergies = synergies(self, state, &visible_effect);
+                let repeal = if self.is_policy() && self.is_online() {
+                    let locked_in = self.locked_in_years(state.world.year);
+                    let is_full = !self.can_downgrade();
+                    if locked_in > 0 || (is_full && self.repeal.has_consequences()) {
+                        Some((self.repeal, locked_in))
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                };
 
                 let can_downgrade = self.kind == ProjectType::Policy && self.level > 0;
                 let has_downgrade = self.is_active() && can_downgrade;
@@ -228,6 +238,73 @@ impl AsCard for Project {
                     scale_text_ui(ui, max_size, move |ui| {
                         render_effects(ui, state, &effects);
 
+                        if let Some(upkeep) = upkeep {
+                            add_tip(
+                                upkeep_tip(&upkeep),
+                                ui.horizontal(|ui| {
+                                    ui.label(t!("Upkeep"));
+                                    ui.label(upkeep.amount().to_string());
+                                    ui.add(upkeep_icon(&upkeep).size(13.));
+                                })
+                                .response,
+                            );
+                        }
+
+                        for synergy in &synergies {
+                            add_tip(
+                                synergy_tip(synergy),
+                                ui.horizontal(|ui| {
+                                    let icon = if synergy.is_conflict {
+                                        icons::WARNING
+                                    } else {
+                                        icons::UNLOCKS
+                                    };
+                                    ui.add(icon.size(13.));
+                                    ui.label(t!("With %{projects}", projects = synergy.others));
+                                    if synergy.is_active {
+                                        ui.add(icons::CHECK.size(13.));
+                                    }
+                                })
+                                .response,
+                            );
+                            match &synergy.interaction {
+                                SynergyDisplay::Effects(effects) => {
+                                    render_effects(ui, state, effects);
+                                }
+                                SynergyDisplay::Scale(name, scale) => {
+                                    ui.label(t!(
+                                        "%{project} effects x%{scale}",
+                                        project = name,
+                                        scale = scale
+                                    ));
+                                }
+                            }
+                        }
+
+                        if let Some((repeal, locked_in)) = &repeal {
+                            add_tip(
+                                repeal_tip(repeal, *locked_in),
+                                ui.horizontal(|ui| {
+                                    if *locked_in > 0 {
+                                        ui.add(icons::LOCKS.size(13.));
+                                        ui.label(t!(
+                                            "Locked in for %{years} yrs",
+                                            years = locked_in
+                                        ));
+                                    } else {
+                                        ui.label(t!("Repeal"));
+                                        ui.label(repeal.cost.to_string());
+                                        ui.add(icons::POLITICAL_CAPITAL.size(13.));
+                                        if repeal.outlook < 0. {
+                                            ui.add(icons::CONTENTEDNESS.size(13.));
+                                            ui.label(format!("{:.0}", repeal.outlook));
+                                        }
+                                    }
+                                })
+                                .response,
+                            );
+                        }
+
                         if is_active && let Some((cost, effects)) = &next_upgrade {
                             if is_upgrading {
                                 ui.label(t!("Upgrading in one planning cycle."));
@@ -262,13 +339,8 @@ impl AsCard for Project {
         // Get the world state version of the project
         // as it will have the latest state.
         let proj = &state.world.projects[&self.id];
-        if proj.is_building() {
-            let rect = egui::Rect::from_min_size(
-                resp.rect.center_bottom() + egui::vec2(-6., -10.),
-                egui::vec2(0., 0.),
-            );
-
-            let building_term = match self.kind {
+        let status_term = if proj.is_building() {
+            Some(match self.kind {
                 ProjectType::Research => {
                     t!("Researching")
                 }
@@ -276,7 +348,17 @@ impl AsCard for Project {
                     t!("Building")
                 }
                 ProjectType::Policy => t!("Passing"),
-            };
+            })
+        } else if proj.is_stalled() {
+            Some(t!("Stalled"))
+        } else {
+            None
+        };
+        if let Some(building_term) = status_term {
+            let rect = egui::Rect::from_min_size(
+                resp.rect.center_bottom() + egui::vec2(-6., -10.),
+                egui::vec2(0., 0.),
+            );
 
             ui.place(rect, |ui: &mut egui::Ui| {
                 egui::Frame::NONE
@@ -338,8 +420,8 @@ impl AsCard for Project {
     }
 }
 
-fn cost_tip(kind: &ProjectType, remaining_cost: &str) -> Tip {
-    match kind {
+fn cost_tip(kind: &ProjectType, remaining_cost: &str, setback_chance: f32) -> Tip {
+    let mut tip = match kind {
         ProjectType::Policy => tip(
             icons::POLITICAL_CAPITAL,
             t!(
@@ -363,18 +445,168 @@ fn cost_tip(kind: &ProjectType, remaining_cost: &str) -> Tip {
                 kind = t!(kind.lower())
             ),
         ),
+    };
+    if *kind != ProjectType::Policy && setback_chance > 0. {
+        let risk = t!(
+            "There's a %{chance}% chance each year of a delay, cost overrun, or failure while it's being built. Labor sabotage, unrest, and opposition make these more likely.",
+            chance = (setback_chance * 100.).round()
+        );
+        tip.text = format!("{} {risk}", tip.text);
+    }
+    tip
+}
+
+fn upkeep_icon(upkeep: &Upkeep) -> Icon {
+    match upkeep {
+        Upkeep::PoliticalCapital(_) => icons::POLITICAL_CAPITAL,
+        Upkeep::Research(_) => icons::RESEARCH,
+    }
+}
+
+fn upkeep_tip(upkeep: &Upkeep) -> Tip {
+    let text = match upkeep {
+        Upkeep::PoliticalCapital(amount) => t!(
+            "Once it's up and running this costs %{amount} political capital every planning cycle to keep going. If you can't pay, it stalls and its effects are suspended until you can.",
+            amount = amount
+        ),
+        Upkeep::Research(amount) => t!(
+            "Once it's up and running this costs %{amount} research points every planning cycle to keep going. If you can't pay, it stalls and its effects are suspended until you can.",
+            amount = amount
+        ),
+    };
+    tip(upkeep_icon(upkeep), text)
+}
+
+fn repeal_tip(repeal: &Repeal, locked_in: usize) -> Tip {
+    if locked_in > 0 {
+        return tip(
+            icons::LOCKS,
+            t!(
+                "This policy is locked in and can't be repealed or rolled back for another %{years} years.",
+                years = locked_in
+            ),
+        );
+    }
+
+    let mut text = t!(
+        "Repealing this policy costs %{cost} political capital.",
+        cost = repeal.cost
+    )
+    .to_string();
+    if repeal.outlook != 0. {
+        let outlook = t!(
+            "It will change the outlook by %{outlook}.",
+            outlook = format!("{:+.0}", repeal.outlook)
+        );
+        text = format!("{text} {outlook}");
+    }
+    if repeal.supporter_backlash > 0. {
+        let backlash = t!("Its supporters will feel betrayed.");
+        text = format!("{text} {backlash}");
+    }
+    if repeal.opposer_approval > 0. {
+        let approval = t!("Its opponents will be pleased.");
+        text = format!("{text} {approval}");
     }
+    tip(icons::POLITICAL_CAPITAL, text)
 }
 
-fn remaining_cost(project: &Project, plan_changes: &BTreeMap<Id, PlanChange>) -> String {
+enum SynergyDisplay {
+    Effects(Vec<DisplayEffect>),
+    Scale(String, f32),
+}
+
+struct SynergyInfo {
+    others: String,
+    is_active: bool,
+    is_conflict: bool,
+    interaction: SynergyDisplay,
+}
+
+/// The synergies and conflicts this project is part of.
+fn synergies(
+    project: &Project,
+    state: &GameState,
+    visible_effect: &impl Fn(&DisplayEffect) -> bool,
+) -> Vec<SynergyInfo> {
+    let projects = &state.world.projects;
+    state
+        .world
+        .synergies
+        .iter()
+        .filter(|synergy| synergy.involves(&project.id))
+        .map(|synergy| {
+            let others = synergy
+                .project_ids()
+                .iter()
+                .filter(|id| **id != project.id)
+                .filter_map(|id| projects.try_get(id))
+                .map(|other| t!(&other.name).to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let interaction = match &synergy.interaction {
+                SynergyInteraction::Effects(effects) => SynergyDisplay::Effects(
+                    effects
+                        .iter()
+                        .map(DisplayEffect::from)
+                        .filter(visible_effect)
+                        .collect(),
+                ),
+                SynergyInteraction::Scale(id, scale) => SynergyDisplay::Scale(
+                    projects
+                        .try_get(id)
+                        .map(|other| t!(&other.name).to_string())
+                        .unwrap_or_default(),
+                    *scale,
+                ),
+            };
+            SynergyInfo {
+                others,
+                is_active: synergy.is_active(projects),
+                is_conflict: synergy.is_conflict(),
+                interaction,
+            }
+        })
+        .collect()
+}
+
+fn synergy_tip(synergy: &SynergyInfo) -> Tip {
+    let text = if synergy.is_conflict {
+        t!(
+            "This works against %{projects}. While they're all online, these effects apply.",
+            projects = synergy.others
+        )
+    } else {
+        t!(
+            "This works together with %{projects}. While they're all online, these effects apply.",
+            projects = synergy.others
+        )
+    };
+    let icon = if synergy.is_conflict {
+        icons::WARNING
+    } else {
+        icons::UNLOCKS
+    };
+    tip(icon, text)
+}
+
+fn remaining_cost(project: &Project, state: &GameState) -> String {
+    let years = |years: usize, likely: usize| {
+        if likely > years {
+            format!("{years}-{likely}")
+        } else {
+            years.to_string()
+        }
+    };
+
     if project.is_online() {
         0.to_string()
     } else if project.is_building() {
         match project.kind {
             ProjectType::Policy => t!("1 planning cycle left").to_string(),
             _ => {
-                let years = project.years_remaining();
-                t!("%{years} yrs left", years = years).to_string()
+                let (remaining, likely) = state.construction_estimate(project);
+                t!("%{years} yrs left", years = years(remaining, likely)).to_string()
             }
         }
     } else {
@@ -385,7 +617,7 @@ fn remaining_cost(project: &Project, plan_changes: &BTreeMap<Id, PlanChange>) ->
         };
         match project.kind {
             ProjectType::Policy => {
-                if let Some(changes) = plan_changes.get(&project.id) {
+                if let Some(changes) = state.ui.plan_changes.get(&project.id) {
                     if changes.withdrawn {
                         0.to_string()
                     } else {
@@ -395,7 +627,10 @@ fn remaining_cost(project: &Project, plan_changes: &BTreeMap<Id, PlanChange>) ->
                     cost.to_string()
                 }
             }
-            _ => t!("%{cost} yrs", cost = cost).to_string(),
+            _ => {
+                let (cost, likely) = state.construction_estimate(project);
+                t!("%{cost} yrs", cost = years(cost, likely)).to_string()
+            }
         }
     }
 }
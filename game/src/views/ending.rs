@@ -22,6 +22,7 @@ pub struct End {
     events: Events,
     badges: Vec<Badge>,
     log: Vec<String>,
+    carbon: String,
     image: Option<ImageSource<'static>>,
 }
 impl End {
@@ -53,6 +54,7 @@ impl End {
             events: Events::new(events, &state.core),
             badges: eval_badges(state),
             log,
+            carbon: summarize_carbon(&state.core),
             image,
         }
     }
@@ -100,7 +102,17 @@ impl End {
                     });
                 });
 
-                ui.add_space(64.);
+                ui.add_space(16.);
+
+                h_center(ui, "carbon", |tui| {
+                    tui.ui(|ui| {
+                        ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Wrap);
+                        ui.set_max_width(width);
+                        ui.label(egui::RichText::new(&self.carbon).color(Color32::WHITE));
+                    });
+                });
+
+                ui.add_space(48.);
 
                 let resp = ui.add(button(t!("Try Again?")).full_width());
                 restart = resp.clicked();
@@ -305,6 +317,26 @@ fn summarize(state: &State, win: bool) -> Summary {
     }
 }
 
+fn summarize_carbon(state: &State) -> String {
+    let budget = &state.carbon_budget;
+    let remaining = budget.remaining_1_5c();
+    if remaining >= 0. {
+        t!(
+            "You emitted %{emitted}Gt CO2eq and kept within the 1.5°C carbon budget, with %{remaining}Gt CO2 to spare.",
+            emitted = budget.co2eq.round(),
+            remaining = remaining.round()
+        )
+        .to_string()
+    } else {
+        t!(
+            "You emitted %{emitted}Gt CO2eq and overshot the 1.5°C carbon budget by %{overshoot}Gt CO2.",
+            emitted = budget.co2eq.round(),
+            overshoot = (-remaining).round()
+        )
+        .to_string()
+    }
+}
+
 fn format_year_log(
     year: usize,
     changes: &[Change],
@@ -2,12 +2,12 @@ use egui::{Color32, Key, Margin, PointerButton};
 use egui_taffy::TuiBuilderLogic;
 use hes_engine::{
     Id, State,
-    flavor::{DialogueLine, DialogueNext, Response, Speaker},
+    flavor::{self, DialogueLine, DialogueNext, Response, Speaker},
 };
 use rust_i18n::t;
 
 use crate::{
-    display::{DisplayEffect, speaker_icon},
+    display::{self, DisplayEffect, speaker_icon},
     parts::{button, r_align},
     text::BbCodeAnimator,
     views::events::render_effects,
@@ -85,6 +85,8 @@ impl Dialogue {
 
         let line = &self.current_line;
         let profile = speaker_icon(&line.speaker);
+        let text = t!(line.text_for(state, self.region_id)).to_string();
+        let text = flavor::interpolate(&text, |key| self.template_value(state, key));
 
         ui.horizontal_top(|ui| {
             if line.speaker != Speaker::Game {
@@ -108,10 +110,6 @@ impl Dialogue {
                             ui.add_space(4.);
                         }
 
-                        let mut text = t!(&line.text).to_string();
-                        if let Some(region_name) = &self.region_name {
-                            text = text.replace("{region}", &t!(region_name.as_str()));
-                        }
                         self.animator.render(ui, &text, width);
                     });
                 });
@@ -161,9 +159,39 @@ impl Dialogue {
                 });
             }
         }
+
+        if result == Some(DialogueResult::Finished)
+            && let Some(event_id) = self.event_id
+        {
+            state.finish_event(event_id);
+        }
         result
     }
 
+    /// Values for placeholders in dialogue text, e.g. `{region}`,
+    /// `{temperature}` or `{npc:The Ecologist.relationship}`.
+    fn template_value(&self, state: &State, key: &str) -> Option<String> {
+        match key {
+            "region" => self
+                .region_name
+                .as_ref()
+                .map(|name| t!(name.as_str()).to_string()),
+            "year" => Some(state.world.year.to_string()),
+            "temperature" => Some(display::temp(state.world.temperature)),
+            "sea_level_rise" => Some(format!("{:.2}m", state.world.sea_level_rise)),
+            "emissions" => Some(display::emissions(state.emissions.as_gtco2eq())),
+            _ => {
+                let (name, attr) = key.strip_prefix("npc:")?.rsplit_once('.')?;
+                let npc = state.npcs.iter().find(|npc| npc.name == name)?;
+                match attr {
+                    "name" => Some(t!(&npc.name).to_string()),
+                    "relationship" => Some(t!(npc.relationship_name()).to_string()),
+                    _ => None,
+                }
+            }
+        }
+    }
+
     fn advance_line(&mut self, state: &State) {
         let next = &self.current_line.next;
         if let Some(next) = next {
@@ -189,6 +217,9 @@ impl Dialogue {
 
     fn select_choice(&mut self, response: &Response, state: &mut State) -> bool {
         state.apply_effects(&response.effects, self.region_id);
+        if let Some(event_id) = self.event_id {
+            state.record_response(event_id, response.id);
+        }
 
         if let Some(line_id) = response.next_line {
             self.current_line = self.dialogue.lines[line_id].clone();
@@ -1,11 +1,14 @@
-use hes_engine::{KindMap, Project, Region, State, Update as EngineUpdate, World};
+use hes_engine::{
+    DisasterImpact, KindMap, Project, Region, Setback, State, TippingPoint, Update as EngineUpdate,
+    World,
+};
 use hes_images::flavor_image;
 use rust_i18n::t;
 
 use super::{AsEventView, Dialogue, EventDetails};
 use crate::{
     display::{
-        AsText, HasIcon, active_effects, icons,
+        self, AsText, DisplayEffect, HasIcon, active_effects, icons,
         intensity::{self, intensity_bar},
         render_effects,
     },
@@ -19,14 +22,20 @@ impl AsEventView for EngineUpdate {
         let (image, attrib) = image_for_update(self, &state.world);
 
         let name = match self {
-            EngineUpdate::Project { id } | EngineUpdate::Policy { id } => {
+            EngineUpdate::Project { id }
+            | EngineUpdate::Policy { id }
+            | EngineUpdate::ProjectUnlocked { id }
+            | EngineUpdate::Setback { id, .. } => {
                 let proj = &state.world.projects[id];
                 &proj.name
             }
-            EngineUpdate::Region { id, .. } => {
+            EngineUpdate::Region { id, .. }
+            | EngineUpdate::Migration { from: id, .. }
+            | EngineUpdate::Disaster { id, .. } => {
                 let region = &state.world.regions[id];
                 &region.name
             }
+            EngineUpdate::TippingPoint { id } => &state.world.tipping_points[id].name,
         };
         EventDetails {
             title,
@@ -64,6 +73,27 @@ impl AsEventView for EngineUpdate {
                 let region = &state.world.regions[id];
                 render_region_outcomes(ui, region, *up, &state.world);
             }
+            EngineUpdate::Migration { from, to, amount } => {
+                let from = &state.world.regions[from];
+                let to = &state.world.regions[to];
+                render_migration_outcomes(ui, from, to, *amount, &state.world);
+            }
+            EngineUpdate::TippingPoint { id } => {
+                let point = &state.world.tipping_points[id];
+                render_tipping_outcomes(ui, point, state);
+            }
+            EngineUpdate::Disaster { id, impact } => {
+                let region = &state.world.regions[id];
+                render_disaster_outcomes(ui, region, impact, &state.world);
+            }
+            EngineUpdate::Setback { id, setback } => {
+                let proj = &state.world.projects[id];
+                render_setback(ui, proj, *setback);
+            }
+            EngineUpdate::ProjectUnlocked { id } => {
+                let proj = &state.world.projects[id];
+                render_unlocked(ui, proj, state);
+            }
         }
     }
 }
@@ -72,23 +102,38 @@ fn title_for_update(update: &EngineUpdate) -> &'static str {
     match update {
         EngineUpdate::Project { .. } => "Project Completed",
         EngineUpdate::Policy { .. } => "Policy Outcome",
+        EngineUpdate::ProjectUnlocked { .. } => "Project Unlocked",
         EngineUpdate::Region { up: true, .. } => "Region Developed",
         EngineUpdate::Region { up: false, .. } => "Region Contracted",
+        EngineUpdate::Migration { .. } => "Climate Migration",
+        EngineUpdate::TippingPoint { .. } => "Tipping Point Crossed",
+        EngineUpdate::Disaster { .. } => "Disaster Toll",
+        EngineUpdate::Setback { .. } => "Construction Setback",
     }
 }
 
 fn image_for_update<'a>(update: &'a EngineUpdate, world: &'a World) -> (egui::Image<'a>, &'a str) {
     match update {
-        EngineUpdate::Project { id } | EngineUpdate::Policy { id } => {
+        EngineUpdate::Project { id }
+        | EngineUpdate::Policy { id }
+        | EngineUpdate::ProjectUnlocked { id }
+        | EngineUpdate::Setback { id, .. } => {
             let proj = &world.projects[id];
             let attrib = proj.flavor.image.attribution.as_str();
             (flavor_image(&proj.flavor.image), attrib)
         }
-        EngineUpdate::Region { id, .. } => {
+        EngineUpdate::Region { id, .. }
+        | EngineUpdate::Migration { from: id, .. }
+        | EngineUpdate::Disaster { id, .. } => {
             let region = &world.regions[id];
             let attrib = region.flavor.image.attribution.as_str();
             (flavor_image(&region.flavor.image), attrib)
         }
+        EngineUpdate::TippingPoint { id } => {
+            let point = &world.tipping_points[id];
+            let attrib = point.image.attribution.as_str();
+            (flavor_image(&point.image), attrib)
+        }
     }
 }
 
@@ -97,6 +142,95 @@ fn render_project_outcomes(ui: &mut egui::Ui, proj: &Project, state: &State) {
     render_effects(ui, state, &effects);
 }
 
+fn render_unlocked(ui: &mut egui::Ui, proj: &Project, state: &State) {
+    let body = t!(
+        "Now that what it depends on is in place, [b]%{project}[/b] is available to plan.",
+        project = t!(&proj.name)
+    );
+    ui.add(bbcode(&body));
+    ui.add_space(12.);
+    render_project_outcomes(ui, proj, state);
+}
+
+fn render_setback(ui: &mut egui::Ui, proj: &Project, setback: Setback) {
+    let body = match setback {
+        Setback::Delay => t!(
+            "Work on [b]%{project}[/b] has stalled this year and made no progress.",
+            project = t!(&proj.name)
+        ),
+        Setback::Overrun => t!(
+            "[b]%{project}[/b] has run over budget and will take longer to finish than planned.",
+            project = t!(&proj.name)
+        ),
+        Setback::Failure => t!(
+            "Part of [b]%{project}[/b] has failed and some of the work done so far will have to be redone.",
+            project = t!(&proj.name)
+        ),
+    };
+    ui.add(bbcode(&body));
+
+    ui.add_space(12.);
+
+    let years = proj.years_remaining();
+    ui.vertical_centered(|ui| {
+        add_tip(
+            tip(
+                icons::TIME,
+                t!("How long the project will take to finish without further setbacks."),
+            ),
+            ui.horizontal(|ui| {
+                ui.add(icons::TIME.size(18.));
+                ui.label(t!("%{years} yrs left", years = years));
+            })
+            .response,
+        );
+    });
+}
+
+fn render_tipping_outcomes(ui: &mut egui::Ui, point: &TippingPoint, state: &State) {
+    ui.add(bbcode(&t!(&point.description)));
+    ui.add_space(12.);
+    let effects: Vec<_> = point.effects.iter().map(DisplayEffect::from).collect();
+    render_effects(ui, state, &effects);
+}
+
+fn render_disaster_outcomes(
+    ui: &mut egui::Ui,
+    region: &Region,
+    impact: &DisasterImpact,
+    world: &World,
+) {
+    let body = t!(
+        "Disasters in [b]%{region}[/b] have killed or displaced about [b]%{deaths}[/b] people this year. Damaged homes and infrastructure will take years to rebuild, and production will be disrupted until they are.",
+        region = t!(&region.name),
+        deaths = display::rounded(impact.deaths),
+    );
+    ui.add(bbcode(&body));
+
+    ui.add_space(12.);
+
+    let habitability_tip = tip(
+        icons::HABITABILITY,
+        t!("The habitability of this region after this year's disasters."),
+    );
+    let habitability = intensity::scale(
+        region.habitability(world.sea_level_rise),
+        intensity::Variable::Habitability,
+    );
+    let spacer = ui.available_width() / 2. - 46.;
+    ui.horizontal(|ui| {
+        ui.add_space(spacer);
+        add_tip(
+            habitability_tip,
+            ui.horizontal(|ui| {
+                ui.add(icons::HABITABILITY.size(18.));
+                ui.add(intensity_bar(habitability).invert());
+            })
+            .response,
+        );
+    });
+}
+
 fn render_region_outcomes(ui: &mut egui::Ui, region: &Region, up: bool, world: &World) {
     let per_capita_demand = &world.per_capita_demand;
 
@@ -206,3 +340,60 @@ fn render_region_outcomes(ui: &mut egui::Ui, region: &Region, up: bool, world: &
         });
     }
 }
+
+fn render_migration_outcomes(
+    ui: &mut egui::Ui,
+    from: &Region,
+    to: &Region,
+    amount: f32,
+    world: &World,
+) {
+    let body = t!(
+        "About [b]%{amount} million[/b] people have left [b]%{from}[/b] this year in search of better living conditions, most of them for [b]%{to}[/b]. Demand in these regions has shifted with them, and both are feeling the strain.",
+        amount = format!("{:.1}", amount / 1e6),
+        from = t!(&from.name),
+        to = t!(&to.name)
+    );
+    ui.add(bbcode(&body));
+
+    ui.add_space(12.);
+
+    let from_tip = tip(
+        icons::HABITABILITY,
+        t!("The habitability of the region people are leaving."),
+    );
+    let to_tip = tip(
+        icons::HABITABILITY,
+        t!("The habitability of the region most people are going to."),
+    );
+    let from_habitability = intensity::scale(
+        from.habitability(world.sea_level_rise),
+        intensity::Variable::Habitability,
+    );
+    let to_habitability = intensity::scale(
+        to.habitability(world.sea_level_rise),
+        intensity::Variable::Habitability,
+    );
+
+    let spacer = ui.available_width() / 2. - 92.;
+    ui.horizontal(|ui| {
+        ui.add_space(spacer);
+        add_tip(
+            from_tip,
+            ui.horizontal(|ui| {
+                ui.add(icons::HABITABILITY.size(18.));
+                ui.add(intensity_bar(from_habitability).invert());
+            })
+            .response,
+        );
+        ui.add(icons::ARROW_RIGHT_LIGHT.size(18.));
+        add_tip(
+            to_tip,
+            ui.horizontal(|ui| {
+                ui.add(icons::HABITABILITY.size(18.));
+                ui.add(intensity_bar(to_habitability).invert());
+            })
+            .response,
+        );
+    });
+}
@@ -1,7 +1,9 @@
 use egui::Color32;
 use egui_extras::{Column, TableBuilder};
 use egui_taffy::TuiBuilderLogic;
-use hes_engine::{EventPhase, IconEvent, Income, NPCRequest, Project};
+use hes_engine::{
+    AgendaRequest, Currency, EventPhase, IconEvent, Income, LedgerSource, NPCRequest, Project,
+};
 use rust_i18n::t;
 
 use crate::{
@@ -25,7 +27,9 @@ pub struct Report {
     events: Events,
     changes: Vec<ChangeRow>,
     projects_finished: Vec<Project>,
+    projects_unlocked: Vec<Project>,
     requests_fulfilled: Vec<(String, isize)>,
+    requests_ignored: Vec<(String, f32)>,
     seat_changes: Vec<(String, f32, f32)>,
     world_events: Vec<(String, Tip)>,
     disasters: Vec<(String, Vec<IconEvent>)>,
@@ -46,19 +50,40 @@ impl Report {
             ghg_row(state),
         ];
 
-        let requests = requests_rows(state);
+        let (requests, requests_ignored) = requests_rows(state);
         let honeymoon_pc = honeymoon_pc(state);
-        let pc_change = changes.iter().map(|row| row.pc_change).sum::<isize>()
-            + requests.iter().map(|(_, bounty)| bounty).sum::<isize>()
-            + (state.ui.cycle_start_state.completed_projects.len()
-                * consts::PC_PER_COMPLETED_PROJECT) as isize
-            + honeymoon_pc;
+        let pc_sources = vec![
+            (
+                LedgerSource::Outlook,
+                changes.iter().map(|row| row.pc_change).sum::<isize>(),
+            ),
+            (
+                LedgerSource::Request,
+                requests.iter().map(|(_, bounty)| bounty).sum::<isize>(),
+            ),
+            (
+                LedgerSource::Completion,
+                (state.ui.cycle_start_state.completed_projects.len()
+                    * consts::PC_PER_COMPLETED_PROJECT) as isize,
+            ),
+            (LedgerSource::Honeymoon, honeymoon_pc),
+        ];
+        let pc_change = pc_sources.iter().map(|(_, amount)| amount).sum();
+
+        // Close out the cycle's ledger once its income is in,
+        // so the report's budget matches the total change.
+        for (source, amount) in &pc_sources {
+            state.change_political_capital(*amount, *source);
+        }
+        state.core.ledger.close_cycle();
 
         Self {
             events: Events::new(events, state),
             changes,
             projects_finished: projects_rows(state),
+            projects_unlocked: unlocked_rows(state),
             requests_fulfilled: requests,
+            requests_ignored,
             seat_changes: parliament_rows(state),
             world_events: event_rows(state),
             disasters: disaster_rows(state),
@@ -99,8 +124,10 @@ impl Report {
 
                         self.render_changes(ui, state);
                         self.render_projects(ui);
+                        self.render_unlocked_projects(ui);
                         self.render_requests(ui);
                         self.render_total_pc_change(ui);
+                        self.render_ledger(ui, state);
 
                         self.render_seat_changes(ui);
                         self.render_world_events(ui);
@@ -110,7 +137,13 @@ impl Report {
                         ui.add_space(16.);
 
                         if ui.add(button(t!("Next")).full_width()).clicked() {
-                            state.change_political_capital(self.pc_change);
+                            // Upkeep counts towards the next cycle's ledger.
+                            // Research upkeep is paid from the points
+                            // the player hasn't spent yet; whatever's left
+                            // is collected again at the start of the session.
+                            state.core.research_points += state.ui.points.research;
+                            state.ui.points.research = 0;
+                            state.core.pay_upkeep();
 
                             // Reset session plan changes
                             state.ui.plan_changes.clear();
@@ -234,6 +267,40 @@ impl Report {
         }
     }
 
+    fn render_unlocked_projects(&self, ui: &mut egui::Ui) {
+        if !self.projects_unlocked.is_empty() {
+            ui.add_space(12.);
+
+            TableBuilder::new(ui)
+                .id_salt("unlocked-projects")
+                .column(Column::remainder())
+                .body(|mut body| {
+                    body.row(ROW_HEIGHT, |mut row| {
+                        row.col(|ui| {
+                            ui.label(
+                                egui::RichText::new(t!("Unlocked Projects"))
+                                    .size(12.)
+                                    .underline(),
+                            );
+                        });
+                    });
+
+                    for p in &self.projects_unlocked {
+                        body.row(ROW_HEIGHT, |mut row| {
+                            row.col(|ui| {
+                                let tip = tip(
+                                    icons::UNLOCKS,
+                                    t!("This project's prerequisites were met."),
+                                )
+                                .card(p.clone());
+                                add_tip(tip, ui.label(t!(&p.name)));
+                            });
+                        });
+                    }
+                });
+        }
+    }
+
     fn render_requests(&self, ui: &mut egui::Ui) {
         if !self.requests_fulfilled.is_empty() {
             TableBuilder::new(ui)
@@ -264,6 +331,37 @@ impl Report {
                     }
                 });
         }
+        if !self.requests_ignored.is_empty() {
+            TableBuilder::new(ui)
+                .id_salt("requests-ignored")
+                .column(Column::remainder())
+                .body(|mut body| {
+                    body.row(ROW_HEIGHT, |mut row| {
+                        row.col(|ui| {
+                            ui.label(
+                                egui::RichText::new(t!("Ignored Requests"))
+                                    .size(12.)
+                                    .underline(),
+                            );
+                        });
+                    });
+
+                    for (name, penalty) in &self.requests_ignored {
+                        body.row(ROW_HEIGHT, |mut row| {
+                            row.col(|ui| {
+                                let tip = tip(
+                                    icons::RELATIONSHIP,
+                                    t!(
+                                        "Ignoring this request cost %{penalty} relationship.",
+                                        penalty = penalty
+                                    ),
+                                );
+                                add_tip(tip, ui.label(name));
+                            });
+                        });
+                    }
+                });
+        }
     }
 
     fn render_total_pc_change(&self, ui: &mut egui::Ui) {
@@ -301,6 +399,61 @@ impl Report {
             });
     }
 
+    fn render_ledger(&self, ui: &mut egui::Ui, state: &GameState) {
+        let pc = state.ledger.previous_breakdown(Currency::PoliticalCapital);
+        let research = state.ledger.previous_breakdown(Currency::Research);
+        if pc.is_empty() && research.is_empty() {
+            return;
+        }
+
+        ui.add_space(12.);
+        TableBuilder::new(ui)
+            .id_salt("ledger")
+            .column(Column::remainder())
+            .column(Column::auto())
+            .column(Column::auto())
+            .body(|mut body| {
+                body.row(ROW_HEIGHT, |mut row| {
+                    row.col(|ui| {
+                        ui.label(egui::RichText::new(t!("Budget")).size(12.).underline());
+                    });
+                    row.col(|ui| {
+                        ui.label(egui::RichText::new(t!("Income")).size(12.));
+                    });
+                    row.col(|ui| {
+                        ui.label(egui::RichText::new(t!("Expenses")).size(12.));
+                    });
+                });
+
+                let rows = pc
+                    .iter()
+                    .map(|row| (icons::POLITICAL_CAPITAL, row))
+                    .chain(research.iter().map(|row| (icons::RESEARCH, row)));
+                for (icon, (source, balance)) in rows {
+                    body.row(ROW_HEIGHT, |mut row| {
+                        row.col(|ui| {
+                            let tip = tip(icon, ledger_source_tip(*source));
+                            let label = ui.horizontal(|ui| {
+                                ui.add(icon.size(16.));
+                                ui.label(ledger_source_label(*source));
+                            });
+                            add_tip(tip, label.response);
+                        });
+                        row.col(|ui| {
+                            if balance.income > 0 {
+                                ui.label(format!("+{}", balance.income));
+                            }
+                        });
+                        row.col(|ui| {
+                            if balance.expenses > 0 {
+                                ui.label(format!("-{}", balance.expenses));
+                            }
+                        });
+                    });
+                }
+            });
+    }
+
     fn render_seat_changes(&self, ui: &mut egui::Ui) {
         if !self.seat_changes.is_empty() {
             ui.add_space(16.);
@@ -585,11 +738,24 @@ fn projects_rows(state: &GameState) -> Vec<Project> {
         .collect::<Vec<_>>()
 }
 
-fn requests_rows(state: &mut GameState) -> Vec<(String, isize)> {
+fn unlocked_rows(state: &GameState) -> Vec<Project> {
+    state
+        .ui
+        .cycle_start_state
+        .unlocked_projects
+        .iter()
+        .map(|project_id| state.world.projects[project_id].clone())
+        .collect()
+}
+
+/// Fulfilled requests with their bounties,
+/// and ignored requests with their penalties.
+fn requests_rows(state: &mut GameState) -> (Vec<(String, isize)>, Vec<(String, f32)>) {
     let finished_requests = state.check_requests();
+    let closed = state.check_agenda_requests();
     let projects = &state.world.projects;
     let processes = &state.world.processes;
-    finished_requests
+    let mut fulfilled = finished_requests
         .into_iter()
         .map(|(kind, id, active, bounty)| match kind {
             NPCRequest::Project => {
@@ -620,7 +786,32 @@ fn requests_rows(state: &mut GameState) -> Vec<(String, isize)> {
                 )
             }
         })
-        .collect::<Vec<_>>()
+        .collect::<Vec<_>>();
+
+    let npc_request = |request: &AgendaRequest| {
+        t!(
+            "%{name}: %{request}",
+            name = t!(&state.npcs[&request.npc_id].name),
+            request = display::demand_text(&request.demand, &state.core)
+        )
+        .to_string()
+    };
+    fulfilled.extend(closed.fulfilled.iter().map(|request| {
+        (
+            t!(
+                "Completed Request: %{request}",
+                request = npc_request(request)
+            )
+            .to_string(),
+            request.bounty as isize,
+        )
+    }));
+    let ignored = closed
+        .expired
+        .iter()
+        .map(|request| (npc_request(request), request.penalty))
+        .collect();
+    (fulfilled, ignored)
 }
 
 fn parliament_rows(state: &GameState) -> Vec<(String, f32, f32)> {
@@ -676,3 +867,37 @@ fn region_rows(state: &GameState) -> Vec<(String, Income)> {
         .map(|(reg, _)| (reg.name.clone(), reg.income))
         .collect::<Vec<_>>()
 }
+
+fn ledger_source_label(source: LedgerSource) -> String {
+    match source {
+        LedgerSource::Event => t!("Events"),
+        LedgerSource::Request => t!("Requests"),
+        LedgerSource::Outlook => t!("World Changes"),
+        LedgerSource::Completion => t!("Completed Projects"),
+        LedgerSource::Honeymoon => t!("Post-Revolution Optimism"),
+        LedgerSource::ProjectCost => t!("Projects"),
+        LedgerSource::Refund => t!("Refunds"),
+        LedgerSource::Upkeep => t!("Upkeep"),
+        LedgerSource::Bargain => t!("Bargains"),
+        LedgerSource::Repeal => t!("Repeals"),
+        LedgerSource::Campaign => t!("Campaigns"),
+    }
+    .to_string()
+}
+
+fn ledger_source_tip(source: LedgerSource) -> String {
+    match source {
+        LedgerSource::Event => t!("Gained or lost through events."),
+        LedgerSource::Request => t!("Earned by fulfilling requests."),
+        LedgerSource::Outlook => t!("Earned or lost for how the world changed."),
+        LedgerSource::Completion => t!("Earned for completing projects."),
+        LedgerSource::Honeymoon => t!("Goodwill from the early years of the revolution."),
+        LedgerSource::ProjectCost => t!("Spent on projects, policies, and upgrades."),
+        LedgerSource::Refund => t!("Returned from withdrawn projects and policies."),
+        LedgerSource::Upkeep => t!("Spent keeping projects running."),
+        LedgerSource::Bargain => t!("Spent winning over parliament."),
+        LedgerSource::Repeal => t!("Spent repealing policies."),
+        LedgerSource::Campaign => t!("Spent campaigning in elections."),
+    }
+    .to_string()
+}
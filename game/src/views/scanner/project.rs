@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 
-use hes_engine::{Flag, Id, Project, ProjectType, Status};
+use hes_engine::{Id, LedgerSource, Project, ProjectType, Status};
 
 use crate::{
     consts,
@@ -18,8 +18,19 @@ fn is_refundable(project: &Project, plan_changes: &BTreeMap<Id, PlanChange>) ->
     }
 }
 
-fn is_subtractable(project: &Project, plan_changes: &BTreeMap<Id, PlanChange>) -> bool {
-    project.is_haltable() || is_refundable(project, plan_changes)
+fn is_subtractable(
+    project: &Project,
+    plan_changes: &BTreeMap<Id, PlanChange>,
+    year: usize,
+) -> bool {
+    if is_refundable(project, plan_changes) {
+        true
+    } else if project.kind == ProjectType::Policy {
+        // Policies can't be repealed while they're locked in.
+        project.can_repeal(year)
+    } else {
+        project.is_haltable()
+    }
 }
 
 impl Scannable for Project {
@@ -96,22 +107,22 @@ impl Scannable for Project {
     }
 
     fn is_add_allowed(&self, state: &GameState) -> bool {
-        let player_seats = state.npcs.coalition_seats();
-        let parliament_suspended = state.flags.contains(&Flag::ParliamentSuspended);
+        let vote_passes = state.simulate_vote(&self.id).passes();
 
         // Does this project already have an upgrade queued/under construction?
         let upgrade_queued = state.ui.queued_upgrades.get(&self.id) == Some(&true);
 
         // Points can be added to a project if:
-        // - The player has enough of a majority, if required.
+        // - Parliament would pass it, if a majority is required.
         // - No upgrade is queued for the project.
         // - An upgrade exists for the project.
         // - If the project is a policy, only if it's not already implemented.
+        // - The project has a target region, if it needs one.
         #[allow(clippy::if_same_then_else)]
         #[allow(clippy::needless_bool)]
-        if (self.required_majority > 0. && player_seats < self.required_majority)
-            && !parliament_suspended
-        {
+        if !vote_passes {
+            false
+        } else if self.needs_target_region() {
             false
         } else if upgrade_queued {
             false
@@ -162,13 +173,22 @@ impl Scannable for Project {
                     state.ui.points.research += excess_points as isize;
                 }
                 state.core.unassign_points(&self.id, points);
-                state.core.change_political_capital(refund as isize);
+                state
+                    .core
+                    .change_political_capital(refund as isize, LedgerSource::Refund);
                 changes.points = 0;
             }
-        } else if self.can_downgrade() {
-            state.core.downgrade_project(&self.id);
-            keep_withdrawing = self.level > 0;
-            changes.downgrades += 1;
+        } else if self.kind == ProjectType::Policy {
+            // Repealing, which may carry some political fallout.
+            if !state.core.repeal_policy(&self.id) {
+                return ScanResult::Rejected;
+            }
+            if self.can_downgrade() {
+                keep_withdrawing = self.level > 0;
+                changes.downgrades += 1;
+            } else {
+                changes.withdrawn = true;
+            }
         } else {
             state.core.stop_project(&self.id);
             changes.withdrawn = true;
@@ -182,6 +202,6 @@ impl Scannable for Project {
     }
 
     fn is_rem_allowed(&self, state: &GameState) -> bool {
-        is_subtractable(self, &state.ui.plan_changes)
+        is_subtractable(self, &state.ui.plan_changes, state.world.year)
     }
 }
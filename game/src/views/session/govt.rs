@@ -2,13 +2,24 @@ use std::{collections::BTreeMap, sync::OnceLock};
 
 use egui::{Color32, CornerRadius, Margin, Pos2, Sense, Shadow, Stroke};
 use egui_taffy::TuiBuilderLogic;
-use hes_engine::{Flag, Id, NPC, State};
+use hes_engine::{
+    CAMPAIGN_COST, Collection, ElectionResult, Flag, Id, MAX_CAMPAIGNS, NPC, ProjectType, State,
+    Vote, VoteResult,
+};
 use rust_i18n::t;
 
 use crate::{
     consts,
-    display::{as_speaker, icons, speaker_icon},
-    parts::{center_center, get_sizing, h_center, overlay, raised_frame, set_full_bg_image},
+    display::{self, as_speaker, icons, speaker_icon},
+    parts::{
+        button,
+        center_center,
+        get_sizing,
+        h_center,
+        overlay,
+        raised_frame,
+        set_full_bg_image,
+    },
     state::GameState,
     tips::{add_tip, tip},
     views::cards::Card,
@@ -61,7 +72,7 @@ impl Parliament {
         }
     }
 
-    pub fn render(&mut self, ui: &mut egui::Ui, state: &GameState) {
+    pub fn render(&mut self, ui: &mut egui::Ui, state: &mut GameState) {
         set_full_bg_image(
             ui,
             hes_images::background_image("parliament.webp"),
@@ -210,10 +221,259 @@ impl Parliament {
             }
 
             ui.add_space(32.);
+
+            // What factions are asking for.
+            if !state.agendas.requests.is_empty() {
+                ui.label(
+                    egui::RichText::new(t!("Requests"))
+                        .heading()
+                        .color(Color32::WHITE),
+                );
+                ui.add_space(16.);
+                render_requests(ui, state);
+                ui.add_space(32.);
+            }
+
+            // Campaigning for the next election.
+            let next_election = state
+                .elections
+                .next(state.world.year, state.world.election_interval);
+            if !suspended && let Some(next) = next_election {
+                ui.label(
+                    egui::RichText::new(t!("Elections"))
+                        .heading()
+                        .color(Color32::WHITE),
+                );
+                ui.add_space(16.);
+                let years = next.saturating_sub(state.world.year);
+                let campaign = render_campaigns(ui, years, state);
+                if let Some(npc_id) = campaign {
+                    state.campaign(&npc_id);
+                }
+                ui.add_space(16.);
+            }
+            if let Some(results) = &state.elections.results {
+                render_election_results(ui, results, &state.npcs, self.total_seats);
+                ui.add_space(32.);
+            }
+
+            // Policies that need a majority to pass.
+            let contested: Vec<(Id, String)> = state
+                .world
+                .projects
+                .unlocked()
+                .filter(|p| {
+                    p.kind == ProjectType::Policy
+                        && p.required_majority > 0.
+                        && !(p.is_building() || p.is_online())
+                })
+                .map(|p| (p.id, p.name.clone()))
+                .collect();
+            if !suspended && !contested.is_empty() {
+                ui.label(
+                    egui::RichText::new(t!("Contested Policies"))
+                        .heading()
+                        .color(Color32::WHITE),
+                );
+                for (project_id, name) in contested {
+                    ui.add_space(16.);
+                    let vote = state.simulate_vote(&project_id);
+                    let bargain = render_vote(ui, &name, &vote, &state.npcs, self.total_seats);
+                    if let Some(npc_id) = bargain {
+                        state.bargain_vote(&project_id, &npc_id);
+                    }
+                }
+                ui.add_space(32.);
+            }
         });
     }
 }
 
+/// Show the vote tally for a policy,
+/// returning the id of the faction to bargain with, if any.
+fn render_vote(
+    ui: &mut egui::Ui,
+    name: &str,
+    vote: &VoteResult,
+    npcs: &Collection<NPC>,
+    total_seats: usize,
+) -> Option<Id> {
+    let mut bargain = None;
+    let seats = |v: Vote| (vote.seats(v) * total_seats as f32).round();
+    raised_frame()
+        .colors(
+            Color32::from_rgb(0xB0, 0x93, 0xBA),
+            Color32::from_rgb(0x4e, 0x2c, 0x59),
+            Color32::from_rgb(0x96, 0x5F, 0xA9),
+        )
+        .margin(Margin::symmetric(12, 8))
+        .show(ui, |ui| {
+            ui.set_width(320.);
+            ui.vertical_centered(|ui| {
+                ui.label(egui::RichText::new(t!(name)).strong());
+                ui.label(t!(
+                    "For: %{yes} · Against: %{no} · Abstain: %{abstain}",
+                    yes = seats(Vote::For),
+                    no = seats(Vote::Against),
+                    abstain = seats(Vote::Abstain)
+                ));
+                if vote.passes() {
+                    ui.label(format!("✓ {}", t!("Would pass")));
+                } else {
+                    ui.label(format!("✗ {}", t!("Would fail")));
+                }
+
+                for faction in &vote.factions {
+                    if let Some(cost) = faction.bargain_cost {
+                        let text = t!(
+                            "Win over %{name} (%{cost} political capital)",
+                            name = t!(&npcs[&faction.npc_id].name),
+                            cost = cost
+                        );
+                        if ui.add(button(text)).clicked() {
+                            bargain = Some(faction.npc_id);
+                        }
+                    }
+                }
+            });
+        });
+    bargain
+}
+
+/// Show the open requests from factions' agendas.
+fn render_requests(ui: &mut egui::Ui, state: &State) {
+    raised_frame()
+        .colors(
+            Color32::from_rgb(0xB0, 0x93, 0xBA),
+            Color32::from_rgb(0x4e, 0x2c, 0x59),
+            Color32::from_rgb(0x96, 0x5F, 0xA9),
+        )
+        .margin(Margin::symmetric(12, 8))
+        .show(ui, |ui| {
+            ui.set_width(320.);
+            ui.vertical_centered(|ui| {
+                for request in &state.agendas.requests {
+                    let npc = &state.npcs[&request.npc_id];
+                    ui.label(
+                        egui::RichText::new(t!(
+                            "%{name}: %{request}",
+                            name = t!(&npc.name),
+                            request = display::demand_text(&request.demand, state)
+                        ))
+                        .strong(),
+                    );
+                    let tip = tip(
+                        icons::RELATIONSHIP,
+                        t!(
+                            "Fulfill this request for %{bounty} political capital. Ignoring it will cost %{penalty} relationship.",
+                            bounty = request.bounty,
+                            penalty = request.penalty
+                        ),
+                    )
+                    .card(npc.clone());
+                    add_tip(
+                        tip,
+                        ui.label(t!(
+                            "%{years} years left",
+                            years = request.years_left(state.world.year)
+                        )),
+                    );
+                }
+            });
+        });
+}
+
+/// Show the factions that can be campaigned for,
+/// returning the id of the faction to campaign for, if any.
+fn render_campaigns(ui: &mut egui::Ui, years: usize, state: &State) -> Option<Id> {
+    let mut campaign = None;
+    raised_frame()
+        .colors(
+            Color32::from_rgb(0xB0, 0x93, 0xBA),
+            Color32::from_rgb(0x4e, 0x2c, 0x59),
+            Color32::from_rgb(0x96, 0x5F, 0xA9),
+        )
+        .margin(Margin::symmetric(12, 8))
+        .show(ui, |ui| {
+            ui.set_width(320.);
+            ui.vertical_centered(|ui| {
+                let next = if years == 0 {
+                    t!("The next election is this year.")
+                } else {
+                    t!("The next election is in %{years} years.", years = years)
+                };
+                add_tip(
+                    tip(
+                        icons::POLITICAL_CAPITAL,
+                        t!(
+                            "Your allies gain seats when the world is content and lose them when it isn't. Campaigning grows a faction's vote in the next election. Events and projects can swing a faction's vote in every election for as long as they last."
+                        ),
+                    ),
+                    ui.label(egui::RichText::new(next).strong()),
+                );
+
+                let can_afford = state.political_capital >= CAMPAIGN_COST as isize;
+                for npc in state.npcs.unlocked() {
+                    let campaigns = state.elections.campaigns(&npc.id);
+                    if can_afford && state.elections.can_campaign(&npc.id) {
+                        let text = t!(
+                            "Campaign for %{name} (%{cost} political capital)",
+                            name = t!(&npc.name),
+                            cost = CAMPAIGN_COST
+                        );
+                        if ui.add(button(text)).clicked() {
+                            campaign = Some(npc.id);
+                        }
+                    } else if campaigns > 0 {
+                        ui.label(t!(
+                            "Campaigned for %{name} (%{campaigns}/%{max})",
+                            name = t!(&npc.name),
+                            campaigns = campaigns,
+                            max = MAX_CAMPAIGNS
+                        ));
+                    }
+                }
+            });
+        });
+    campaign
+}
+
+/// Show how the seats changed in the last election.
+fn render_election_results(
+    ui: &mut egui::Ui,
+    results: &ElectionResult,
+    npcs: &Collection<NPC>,
+    total_seats: usize,
+) {
+    let seats = |share: f32| (share * total_seats as f32).round() as isize;
+    raised_frame()
+        .colors(
+            Color32::from_rgb(0xB0, 0x93, 0xBA),
+            Color32::from_rgb(0x4e, 0x2c, 0x59),
+            Color32::from_rgb(0x96, 0x5F, 0xA9),
+        )
+        .margin(Margin::symmetric(12, 8))
+        .show(ui, |ui| {
+            ui.set_width(320.);
+            ui.vertical_centered(|ui| {
+                ui.label(
+                    egui::RichText::new(t!("Results of the %{year} Election", year = results.year))
+                        .strong(),
+                );
+                for faction in &results.factions {
+                    let after = seats(faction.seats);
+                    let change = after - seats(faction.seats_before);
+                    ui.label(t!(
+                        "%{name}: %{seats} seats (%{change})",
+                        name = t!(&npcs[&faction.npc_id].name),
+                        seats = after,
+                        change = format!("{change:+}")
+                    ));
+                }
+            });
+        });
+}
+
 struct Seats {
     id: Id,
     name: String,
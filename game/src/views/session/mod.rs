@@ -183,7 +183,9 @@ impl Session {
             View::Stats(stats) => {
                 stats.render(ui, &state.core, &state.ui.process_mix_changes);
             }
-            View::World(regions) => regions.render(ui, &state.core, &state.ui.annual_region_events),
+            View::World(regions) => {
+                regions.render(ui, &mut state.core, &state.ui.annual_region_events);
+            }
         }
 
         go_to_world
@@ -4,8 +4,8 @@ use egui::{Align2, Color32, CornerRadius, Margin, Order, Rect, Sense, Shadow, St
 use egui_taffy::TuiBuilderLogic;
 use enum_map::EnumMap;
 use hes_engine::{
-    EventPhase, Feedstock, Id, KindMap, Output, Process, Project, ProjectType, Resource, State,
-    Status,
+    EventPhase, Feedstock, Id, KindMap, Output, Prerequisite, Process, Project,
+    ProjectDependencyKind, ProjectGraph, ProjectType, Resource, State, Status,
 };
 use hes_images::flavor_image;
 use rust_i18n::t;
@@ -217,6 +217,8 @@ impl Plan {
             });
         });
 
+        render_upkeep(ui, state);
+
         // next section (production)
         let prod_shortages = production_shortages(state);
         let inp_shortages = input_shortages(state);
@@ -495,6 +497,56 @@ impl Page {
     }
 }
 
+/// What the plan costs to keep going each planning cycle.
+fn render_upkeep(ui: &mut egui::Ui, state: &State) {
+    let (pc, research) = state.upkeep_due();
+    let stalled: Vec<_> = state
+        .world
+        .projects
+        .iter()
+        .filter(|p| p.is_stalled())
+        .map(|p| t!(&p.name))
+        .collect();
+    if pc == 0 && research == 0 && stalled.is_empty() {
+        return;
+    }
+
+    let mut text = t!(
+        "Keeping your plan going costs %{pc} political capital and %{research} research points each planning cycle. Projects you can't pay for stall and lose their effects until you can, and are halted if they go unpaid for too long.",
+        pc = pc,
+        research = research
+    )
+    .to_string();
+    if !stalled.is_empty() {
+        text.push_str(&format!("\n\n{} {}", t!("Stalled:"), stalled.join(", ")));
+    }
+    let upkeep_tip = tip(icons::POLITICAL_CAPITAL, text);
+
+    ui.add_space(24.);
+    h_center(ui, "plan-upkeep", |tui| {
+        tui.ui(|ui| {
+            let resp = inset_frame().show(ui, |ui| {
+                ui.style_mut().visuals.override_text_color = Some(Color32::BLACK);
+                ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Extend);
+                ui.horizontal(|ui| {
+                    ui.label(t!("Upkeep per cycle"));
+                    ui.add_space(8.);
+                    ui.add(icons::POLITICAL_CAPITAL.size(16.));
+                    ui.label(pc.to_string());
+                    ui.add(icons::RESEARCH.size(16.));
+                    ui.label(research.to_string());
+                    if !stalled.is_empty() {
+                        ui.add_space(8.);
+                        ui.add(icons::ALERT.size(16.));
+                        ui.label(t!("%{count} stalled", count = stalled.len()));
+                    }
+                });
+            });
+            add_tip(upkeep_tip, resp);
+        });
+    });
+}
+
 fn calc_slots(ui: &mut egui::Ui) -> usize {
     let width = ui.ctx().content_rect().width();
     if width > 680. {
@@ -597,7 +649,7 @@ fn production_shortages(state: &State) -> Option<String> {
 
 fn render_resource_status(ui: &mut egui::Ui, state: &State, shortages_tip: Option<Tip>) {
     let resources = &state.resources;
-    let protected_land = state.protected_land;
+    let reserved_land = state.land.reserved.sum();
     let resource_demand = &state.resource_demand;
     let starting_resources = state.world.starting_resources;
 
@@ -607,9 +659,9 @@ fn render_resource_status(ui: &mut egui::Ui, state: &State, shortages_tip: Optio
                 Resource::Electricity | Resource::Fuel => to_energy_units(demand),
                 Resource::Water => resource(demand, k, resources.available),
                 Resource::Land => {
-                    // For land we add in protected land as well.
-                    let protected = protected_land * 100.;
-                    resource(demand, k, starting_resources) + protected
+                    // For land we add in protected and other reserved land as well.
+                    let reserved = reserved_land * 100.;
+                    resource(demand, k, starting_resources) + reserved
                 }
             };
             let available = match k {
@@ -947,6 +999,7 @@ enum ProjectsAction {
 struct Projects {
     kind: ProjectType,
     cards: Cards<Project>,
+    show_tree: bool,
 }
 impl Projects {
     fn new(state: &State, plan_changes: &BTreeMap<Id, PlanChange>) -> Self {
@@ -954,6 +1007,7 @@ impl Projects {
         Self {
             kind,
             cards: Cards::new(get_projects(state, &kind, plan_changes).into_iter()),
+            show_tree: false,
         }
     }
 
@@ -1005,9 +1059,30 @@ impl Projects {
             }
         }
 
-        let changed = self.cards.render(ui, state);
-        if changed {
-            action = Some(ProjectsAction::Changed);
+        let show_tree = self.show_tree && self.kind == ProjectType::Research;
+        if show_tree {
+            render_tech_tree(ui, &state.core);
+        } else {
+            let changed = self.cards.render(ui, state);
+            if changed {
+                action = Some(ProjectsAction::Changed);
+            }
+        }
+
+        if self.kind == ProjectType::Research {
+            egui::Area::new("tech-tree-toggle".into())
+                .anchor(Align2::LEFT_BOTTOM, egui::vec2(12., -12.))
+                .order(Order::Foreground)
+                .show(ui.ctx(), |ui| {
+                    let label = if show_tree {
+                        t!("View Projects")
+                    } else {
+                        t!("View Tech Tree")
+                    };
+                    if ui.add(button(label)).clicked() {
+                        self.show_tree = !self.show_tree;
+                    }
+                });
         }
 
         egui::Area::new("project-points".into())
@@ -1065,6 +1140,121 @@ fn get_projects(
     projects
 }
 
+const TREE_NODE_WIDTH: f32 = 140.;
+
+/// Research laid out by how many steps it is from
+/// the start, with what each project is waiting on.
+/// Locked research is only shown if it's part of the tree,
+/// so projects unlocked by surprise events stay a surprise.
+fn render_tech_tree(ui: &mut egui::Ui, state: &State) {
+    let graph = state.world.project_graph();
+    let research = state.world.projects.iter().filter(|p| {
+        p.kind == ProjectType::Research
+            && (!p.locked || !graph.dependencies(&p.id).is_empty() || DEBUG.show_all_projects)
+    });
+    let tiers = graph.tiers(research.map(|p| p.id));
+
+    egui::ScrollArea::both().show(ui, |ui| {
+        ui.add_space(24.);
+        ui.horizontal_top(|ui| {
+            ui.add_space(24.);
+            ui.style_mut().spacing.item_spacing = egui::vec2(32., 8.);
+            for tier in tiers.iter().filter(|tier| !tier.is_empty()) {
+                ui.vertical(|ui| {
+                    ui.set_width(TREE_NODE_WIDTH);
+                    for id in tier {
+                        render_tree_node(ui, state, &state.world.projects[id], &graph);
+                    }
+                });
+            }
+        });
+        ui.add_space(64.);
+    });
+}
+
+fn render_tree_node(ui: &mut egui::Ui, state: &State, project: &Project, graph: &ProjectGraph) {
+    let (bg, fg) = group_color(&project.group);
+    let resp = egui::Frame::NONE
+        .fill(bg)
+        .corner_radius(4)
+        .inner_margin(6)
+        .show(ui, |ui| {
+            ui.set_width(TREE_NODE_WIDTH - 12.);
+            if project.locked {
+                ui.set_opacity(0.5);
+            }
+            ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Wrap);
+            ui.horizontal(|ui| {
+                if project.is_finished() || project.is_active() {
+                    ui.add(icons::CHECK.size(14.));
+                } else if project.locked {
+                    ui.add(icons::LOCKS.size(14.));
+                }
+                ui.label(egui::RichText::new(t!(&project.name)).color(fg));
+            });
+            if project.is_building() {
+                ui.add(fill_bar((TREE_NODE_WIDTH - 12., 4.), project.progress));
+            }
+        })
+        .response;
+
+    let mut requires: Vec<String> = project
+        .prerequisites
+        .iter()
+        .map(|prereq| format!("- {}", prerequisite_text(prereq, state)))
+        .collect();
+    for dep in graph.dependencies(&project.id) {
+        if dep.kind == ProjectDependencyKind::Unlock {
+            let other = &state.world.projects[&dep.project];
+            requires.push(format!(
+                "- {}",
+                t!("Unlocked by [b]%{project}[/b]", project = t!(&other.name))
+            ));
+        }
+    }
+    let leads_to: Vec<String> = graph
+        .dependents(&project.id)
+        .iter()
+        .map(|dep| format!("- [b]{}[/b]", t!(&state.world.projects[&dep.project].name)))
+        .collect();
+
+    let mut text = format!("[b]{}[/b]", t!(&project.name));
+    if !requires.is_empty() {
+        text.push_str(&format!("\n{}\n{}", t!("Requires:"), requires.join("\n")));
+    }
+    if !leads_to.is_empty() {
+        text.push_str(&format!("\n{}\n{}", t!("Leads to:"), leads_to.join("\n")));
+    }
+    add_tip(tip(icons::RESEARCH, text), resp);
+}
+
+fn prerequisite_text(prereq: &Prerequisite, state: &State) -> String {
+    match prereq {
+        Prerequisite::ProjectStatus(id, status) => {
+            let project = t!(&state.world.projects[id].name);
+            match status {
+                Status::Active | Status::Finished => {
+                    t!("Complete [b]%{project}[/b]", project = project)
+                }
+                Status::Building => t!("Start [b]%{project}[/b]", project = project),
+                _ => t!(
+                    "[b]%{project}[/b] is %{status}",
+                    project = project,
+                    status = t!(status.to_string()).to_lowercase()
+                ),
+            }
+        }
+        Prerequisite::ProjectLevel(id, level) => t!(
+            "Upgrade [b]%{project}[/b] to level %{level}",
+            project = t!(&state.world.projects[id].name),
+            level = level
+        ),
+        Prerequisite::HasFlag(_) => t!("Something else has to happen first"),
+        Prerequisite::Year(year) => t!("Not before %{year}", year = year),
+    }
+    .to_string()
+}
+
 const SLOT_HEIGHT: f32 = 155.;
 const SLOT_WIDTH: f32 = 105.;
 
@@ -3,7 +3,7 @@ use std::{collections::BTreeMap, sync::Arc};
 use egui::{Color32, Margin, Sense};
 use egui_taffy::TuiBuilderLogic;
 use enum_map::EnumMap;
-use hes_engine::{IconEvent, Id, KindMap, Output, Region, State};
+use hes_engine::{IconEvent, Id, KindMap, Output, ProjectTarget, Region, State, Status};
 use hes_images::flavor_image;
 use rust_i18n::t;
 use strum::IntoEnumIterator;
@@ -39,7 +39,7 @@ impl Regions {
     pub fn render(
         &mut self,
         ui: &mut egui::Ui,
-        state: &State,
+        state: &mut State,
         region_events: &BTreeMap<Id, Vec<IconEvent>>,
     ) {
         set_full_bg_image(
@@ -104,6 +104,13 @@ impl Regions {
         });
 
         render_region_item(ui, region, state, region_events);
+
+        let region_id = region.id;
+        h_center(ui, "region-projects", |tui| {
+            tui.ui(|ui| {
+                region_projects(ui, &region_id, state);
+            });
+        });
     }
 
     fn center_on_region(&mut self) {
@@ -125,6 +132,13 @@ fn precip_tip() -> Tip {
     )
 }
 
+fn risk_tip() -> Tip {
+    tip(
+        icons::WARMING,
+        t!("The chance of heatwaves, droughts, and floods in this region this year."),
+    )
+}
+
 fn devel_tip() -> Tip {
     tip(
         icons::DEVELOPMENT,
@@ -140,7 +154,7 @@ fn hab_tip() -> Tip {
     tip(
         icons::HABITABILITY,
         t!(
-            "This region's habitability. Natural disasters and hotter temperatures lower habitability."
+            "This region's habitability. Natural disasters, hotter temperatures, droughts, floods, and rising seas lower habitability."
         ),
     )
 }
@@ -244,6 +258,25 @@ fn region_preview(
                 );
             });
 
+            let risks = region.climate_risks;
+            add_tip(
+                risk_tip(),
+                ui.horizontal(|ui| {
+                    ui.style_mut().spacing.item_spacing.x = 2.;
+                    for (icon, risk) in [
+                        (icons::HEATWAVE, risks.heatwave),
+                        (icons::CROP_FAILURE, risks.drought),
+                        (icons::FLOOD, risks.flood),
+                    ] {
+                        ui.add(icon.size(12.));
+                        let text = format!("{}%", display::percent(risk, true));
+                        ui.label(egui::RichText::new(text).size(12.));
+                        ui.add_space(4.);
+                    }
+                })
+                .response,
+            );
+
             ui.add_space(8.);
 
             let is_max_level = region.is_max_income();
@@ -278,7 +311,10 @@ fn region_preview(
 
 fn region_impacts(ui: &mut egui::Ui, region: &Region, state: &State) {
     let contentedness = intensity::scale(region.outlook, intensity::Variable::Outlook);
-    let habitability = intensity::scale(region.habitability(), intensity::Variable::Habitability);
+    let habitability = intensity::scale(
+        region.habitability(state.world.sea_level_rise),
+        intensity::Variable::Habitability,
+    );
     let income_tip = {
         let name = t!(region.income.lower());
         inc_tip(&name)
@@ -340,6 +376,81 @@ fn region_impacts(ui: &mut egui::Ui, region: &Region, state: &State) {
     });
 }
 
+/// Projects targeting this region, and
+/// region-targeted projects that could be directed here.
+fn region_projects(ui: &mut egui::Ui, region_id: &Id, state: &mut State) {
+    let region = &state.world.regions[region_id];
+    let targeting: Vec<String> = state
+        .world
+        .projects
+        .iter()
+        .filter(|project| {
+            project.target.is_some()
+                && project.targets(region)
+                && (project.is_active() || project.is_online())
+        })
+        .map(|project| t!(&project.name).to_string())
+        .collect();
+    let targetable: Vec<(Id, String, bool)> = if region.seceded {
+        vec![]
+    } else {
+        state
+            .world
+            .projects
+            .iter()
+            .filter(|project| {
+                !project.locked
+                    && project.target == Some(ProjectTarget::Region)
+                    && project.status == Status::Inactive
+            })
+            .map(|project| {
+                (
+                    project.id,
+                    t!(&project.name).to_string(),
+                    project.targets(region),
+                )
+            })
+            .collect()
+    };
+
+    let mut retarget = None;
+    inset_frame().show(ui, |ui| {
+        ui.vertical(|ui| {
+            ui.style_mut().visuals.override_text_color = Some(Color32::WHITE);
+
+            ui.label(egui::RichText::new(t!("Regional Projects")).underline());
+            if targeting.is_empty() {
+                ui.label(egui::RichText::new("---").color(Color32::from_white_alpha(64)));
+            }
+            for name in &targeting {
+                ui.label(name);
+            }
+
+            if !targetable.is_empty() {
+                ui.add_space(8.);
+                ui.label(egui::RichText::new(t!("Direct a Project Here")).underline());
+                for (id, name, targeted) in &targetable {
+                    if *targeted {
+                        ui.label(format!("{name} ({})", t!("Targeted")));
+                    } else if button_frame()
+                        .show(ui, |ui| {
+                            ui.label(egui::RichText::new(name).color(Color32::BLACK));
+                        })
+                        .interact(Sense::click())
+                        .clicked()
+                    {
+                        retarget = Some(*id);
+                    }
+                }
+            }
+        });
+    });
+
+    if let Some(project_id) = retarget {
+        state.set_project_target(&project_id, region_id);
+    }
+}
+
 fn render_devel_bar(ui: &mut egui::Ui, is_max_level: bool, development: f32) {
     if is_max_level {
         ui.label(t!("Max Level"));
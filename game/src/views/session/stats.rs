@@ -99,6 +99,9 @@ impl Stats {
                     tui.ui(|ui| {
                         render_emissions(ui, state, &process_changes);
                     });
+                    tui.ui(|ui| {
+                        stat(ui, |ui| render_carbon_budget(ui, state), t!("1.5°C Budget"));
+                    });
                 });
 
                 ui.add_space(32.);
@@ -164,6 +167,9 @@ impl Stats {
                     tui.ui(|ui| {
                         render_emissions(ui, state, &process_changes);
                     });
+                    tui.ui(|ui| {
+                        stat(ui, |ui| render_carbon_budget(ui, state), t!("1.5°C Budget"));
+                    });
                     tui.ui(|ui| {
                         render_land(ui, state, &process_changes);
                     });
@@ -253,6 +259,9 @@ impl Stats {
                     tui.ui(|ui| {
                         render_emissions(ui, state, &process_changes);
                     });
+                    tui.ui(|ui| {
+                        stat(ui, |ui| render_carbon_budget(ui, state), t!("1.5°C Budget"));
+                    });
                     tui.ui(|ui| {
                         render_land(ui, state, &process_changes);
                     });
@@ -388,6 +397,36 @@ fn render_emissions(ui: &mut egui::Ui, state: &State, process_changes: &[(Proces
     );
 }
 
+fn render_carbon_budget(ui: &mut egui::Ui, state: &State) {
+    let budget = &state.carbon_budget;
+    let remaining = budget.remaining_1_5c();
+    let net_zero = match budget.net_zero_year(state.world.year) {
+        Some(year) => t!(
+            "At the current pace emissions will reach net-zero in %{year}.",
+            year = year
+        ),
+        None => t!("Emissions aren't falling, so net-zero isn't in sight."),
+    };
+    let tip_text = t!(
+        "%{cumulative}Gt CO2eq has been emitted since you took over. %{remaining}Gt CO2 is left in the budget for keeping warming to 1.5°C, and %{remainingTwo}Gt CO2 for 2°C. %{netZero}",
+        cumulative = budget.co2eq.round(),
+        remaining = remaining.max(0.).round(),
+        remainingTwo = budget.remaining_2c().max(0.).round(),
+        netZero = net_zero
+    );
+    let tip: Tip = tip(icons::EMISSIONS, tip_text);
+
+    add_tip(
+        tip,
+        ui.vertical_centered(|ui| {
+            ui.add(icons::EMISSIONS.size(24.));
+            ui.add_space(8.);
+            render_stat_text(ui, &format!("{:.0}Gt", remaining), None);
+        })
+        .response,
+    );
+}
+
 fn render_land(ui: &mut egui::Ui, state: &State, process_changes: &[(Process, f32)]) {
     let available_land = state.world.starting_resources.land;
     let tip = tip(icons::LAND, t!("Current land use.")).card(factors_card(None, Var::Land, state));
@@ -564,7 +603,7 @@ fn render_income(ui: &mut egui::Ui, state: &State) {
 }
 
 fn render_habitability(ui: &mut egui::Ui, state: &State) {
-    let habitability = state.world.regions.habitability().max(0.);
+    let habitability = state.world.regions.habitability(state.world.sea_level_rise).max(0.);
     let habitability = {
         let int = intensity::scale(habitability, intensity::Variable::Habitability);
         MiniCardData {
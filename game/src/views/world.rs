@@ -227,6 +227,15 @@ impl WorldEvents {
                 .cycle_start_state
                 .completed_projects
                 .extend(completed_projects);
+            let unlocked_projects = step_updates.iter().filter_map(|update| match update {
+                EngineUpdate::ProjectUnlocked { id } => Some(id),
+                _ => None,
+            });
+            state
+                .ui
+                .cycle_start_state
+                .unlocked_projects
+                .extend(unlocked_projects);
 
             self.updates = Updates::new(step_updates, &state.core);
         }